@@ -15,5 +15,35 @@ fn main() -> io::Result<()> {
 	}
 	dotenv.flush()?;
 
+	#[cfg(feature = "embedded-data")]
+	embed_data()?;
+
 	Ok(())
 }
+
+/// Validate the dataset and encode it for [`include_bytes`]
+#[cfg(feature = "embedded-data")]
+fn embed_data() -> io::Result<()> {
+	use dal_spirits_models::{binary, lint};
+	use std::path::PathBuf;
+
+	const DATA_PATH: &str = "assets/spirits.csv";
+	println!("cargo:rerun-if-changed={DATA_PATH}");
+
+	let csv = std::fs::read_to_string(DATA_PATH)?;
+	let report = lint::lint(&csv);
+	for diag in report.warnings() {
+		println!("cargo:warning={DATA_PATH}:{diag}");
+	}
+	if report.has_errors() {
+		for diag in report.errors() {
+			eprintln!("{DATA_PATH}:{diag}");
+		}
+		panic!("invalid dataset \"{DATA_PATH}\"");
+	}
+
+	let bytes = binary::to_bytes(&report.spirits)
+		.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+	let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").expect("missing OUT_DIR"));
+	std::fs::write(out_dir.join("spirits.bin"), bytes)
+}
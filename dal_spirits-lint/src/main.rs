@@ -0,0 +1,37 @@
+#![forbid(unsafe_code)]
+#![deny(unused_must_use)]
+
+use dal_spirits_models::lint;
+use std::process::ExitCode;
+
+const DEFAULT_PATH: &str = "assets/spirits.csv";
+
+fn main() -> ExitCode {
+	let path = std::env::args()
+		.nth(1)
+		.unwrap_or_else(|| DEFAULT_PATH.to_string());
+	let csv = match std::fs::read_to_string(&path) {
+		Ok(csv) => csv,
+		Err(err) => {
+			eprintln!("{path}: {err}");
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let report = lint::lint(&csv);
+	for diag in &report.diagnostics {
+		eprintln!("{path}:{diag}");
+	}
+	eprintln!(
+		"{path}: {count} spirits, {errors} errors, {warnings} warnings",
+		count = report.spirits.len(),
+		errors = report.errors().count(),
+		warnings = report.warnings().count()
+	);
+
+	if report.has_errors() {
+		ExitCode::FAILURE
+	} else {
+		ExitCode::SUCCESS
+	}
+}
@@ -0,0 +1,17 @@
+//! Compact binary encoding of the dataset
+//!
+//! It is meant to be produced at build time and embedded into the web application,
+//! so that no text parsing happens at runtime.
+use crate::Spirit;
+
+pub use postcard::Error;
+
+#[inline]
+pub fn to_bytes(spirits: &[Spirit]) -> Result<Vec<u8>, Error> {
+	postcard::to_allocvec(spirits)
+}
+
+#[inline]
+pub fn from_bytes(bytes: &[u8]) -> Result<Vec<Spirit>, Error> {
+	postcard::from_bytes(bytes)
+}
@@ -0,0 +1,87 @@
+use crate::Month;
+use std::fmt::{self, Display, Formatter};
+
+/// Failure to parse a [`Birthdate`](crate::Birthdate)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BirthdateError {
+	/// The text does not look like `<month> <day>`
+	Format,
+	InvalidMonth(String),
+	InvalidDay(String),
+	/// The day does not exist in the month
+	NoSuchDay(Month, u8),
+}
+impl Display for BirthdateError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Format => write!(f, "could not find date"),
+			Self::InvalidMonth(month) => write!(f, "invalid month {month:?}"),
+			Self::InvalidDay(day) => write!(f, "invalid day {day:?}"),
+			Self::NoSuchDay(month, day) => write!(f, "{month} has no day {day}"),
+		}
+	}
+}
+impl std::error::Error for BirthdateError {}
+
+/// Invalid value in a record of the dataset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+	/// Line number in CSV, starting at 1 with the header, or record number in other formats
+	pub row: usize,
+	/// First name of the character, if it could be read
+	pub name: Option<String>,
+	/// Name of the column, if the error could be pinpointed
+	pub column: Option<String>,
+	pub message: String,
+}
+impl Display for FieldError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "row {row}", row = self.row)?;
+		if let Some(ref name) = self.name {
+			write!(f, " ({name})")?;
+		}
+		if let Some(ref column) = self.column {
+			write!(f, ", column \"{column}\"")?;
+		}
+		write!(f, ": {msg}", msg = self.message)
+	}
+}
+impl std::error::Error for FieldError {}
+
+/// Failure to load the dataset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+	/// The dataset could not be requested
+	Network(String),
+	/// The server answered with an unsuccessful status
+	HttpStatus { status: u16, reason: String },
+	/// The dataset could not be read
+	Io(String),
+	/// The dataset is not well-formed
+	Syntax(String),
+	/// Some records hold invalid values
+	Validation(Vec<FieldError>),
+}
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Network(msg) => write!(f, "network failure: {msg}"),
+			Self::HttpStatus { status, reason } => {
+				write!(f, "the server answered with status {status} {reason}")
+			}
+			Self::Io(msg) => write!(f, "could not read the dataset: {msg}"),
+			Self::Syntax(msg) => write!(f, "malformed dataset: {msg}"),
+			Self::Validation(errors) => {
+				write!(f, "{count} invalid values", count = errors.len())?;
+				errors.iter().try_for_each(|err| write!(f, "\n{err}"))
+			}
+		}
+	}
+}
+impl std::error::Error for Error {}
+impl From<FieldError> for Error {
+	#[inline]
+	fn from(err: FieldError) -> Self {
+		Self::Validation(vec![err])
+	}
+}
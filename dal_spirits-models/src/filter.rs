@@ -0,0 +1,174 @@
+//! Criteria to narrow down the list of spirits
+use crate::{CharFlags, Class, Element, Gender, Medium, Spirit};
+
+const KEY_ELEMENTS: &str = "elements";
+const KEY_MEDIA: &str = "media";
+const KEY_CLASS: &str = "class";
+const KEY_RANK: &str = "rank";
+const KEY_GENDER: &str = "gender";
+const KEY_COLLAB: &str = "collab";
+
+/// Set of criteria that spirits must all meet
+///
+/// The default filter lets every spirit through.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Filter {
+	/// Spirits must have one of these elements, if any
+	pub elements: CharFlags<Element>,
+	/// Spirits must appear in one of these media, if any
+	pub media: CharFlags<Medium>,
+	/// Lowest [`Spirit::class`] allowed
+	pub min_class: Option<Class>,
+	/// Lowest [`Spirit::rank`] allowed
+	pub min_rank: Option<Class>,
+	pub gender: Option<Gender>,
+	/// Whether spirits must come from a collaboration [series](crate::Series::is_collab), or must not
+	pub collab: Option<bool>,
+}
+impl Filter {
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+
+	pub fn matches(&self, spirit: &Spirit) -> bool {
+		let at_least = |min: Option<Class>, value: Option<Class>| {
+			min.map_or(true, |min| value >= Some(min))
+		};
+
+		(self.elements.is_empty() || self.elements.intersects(*spirit.elements))
+			&& (self.media.is_empty() || self.media.intersects(*spirit.media))
+			&& at_least(self.min_class, spirit.class)
+			&& at_least(self.min_rank, spirit.rank)
+			&& self.gender.map_or(true, |gender| spirit.gender() == gender)
+			&& self
+				.collab
+				.map_or(true, |collab| spirit.series().is_collab() == collab)
+	}
+
+	/// Encode the criteria as key-value pairs, for the query string of a URL
+	///
+	/// Criteria which let every spirit through are left out.
+	pub fn to_query(&self) -> Vec<(&'static str, String)> {
+		let mut query = Vec::new();
+		if !self.elements.is_empty() {
+			query.push((KEY_ELEMENTS, self.elements.to_string()));
+		}
+		if !self.media.is_empty() {
+			query.push((KEY_MEDIA, self.media.to_string()));
+		}
+		if let Some(class) = self.min_class {
+			query.push((KEY_CLASS, class.to_string()));
+		}
+		if let Some(rank) = self.min_rank {
+			query.push((KEY_RANK, rank.to_string()));
+		}
+		if let Some(gender) = self.gender {
+			query.push((KEY_GENDER, gender.to_string()));
+		}
+		if let Some(collab) = self.collab {
+			query.push((KEY_COLLAB, collab.to_string()));
+		}
+		query
+	}
+
+	/// Decode the criteria from key-value pairs made by [`to_query`](Self::to_query)
+	///
+	/// Unknown keys and invalid values are ignored.
+	pub fn from_query<'q, I>(query: I) -> Self
+	where
+		I: IntoIterator<Item = (&'q str, &'q str)>,
+	{
+		let mut filter = Self::default();
+		for (key, value) in query {
+			if value.is_empty() {
+				continue;
+			}
+			match key {
+				KEY_ELEMENTS => filter.elements = value.parse().unwrap_or_default(),
+				KEY_MEDIA => filter.media = value.parse().unwrap_or_default(),
+				KEY_CLASS => filter.min_class = value.parse().ok(),
+				KEY_RANK => filter.min_rank = value.parse().ok(),
+				KEY_GENDER => filter.gender = value.parse().ok(),
+				KEY_COLLAB => filter.collab = value.parse().ok(),
+				_ => {}
+			}
+		}
+		filter
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Filter;
+	use crate::{Class, Element, Gender, Medium, Spirit};
+	use enumflags2::BitFlags;
+	use serde_json::json;
+
+	fn spirit(class: Option<Class>, rank: Option<Class>) -> Spirit {
+		crate::test_utils::spirit(json!({
+			"class": class,
+			"rank": rank,
+			"elements": "p",
+		}))
+	}
+
+	#[test]
+	fn query_round_trip() {
+		let filter = Filter {
+			elements: (Element::Physical | Element::Frost).into(),
+			media: BitFlags::from(Medium::Anime).into(),
+			min_class: Some(Class::AA),
+			min_rank: Some(Class::S),
+			gender: Some(Gender::Male),
+			collab: Some(false),
+		};
+		let query = filter.to_query();
+		assert_eq!(query.len(), 6);
+		let query = query.iter().map(|(key, value)| (*key, value.as_str()));
+		assert_eq!(Filter::from_query(query), filter);
+
+		assert!(Filter::default().to_query().is_empty());
+		assert_eq!(Filter::from_query([]), Filter::default());
+	}
+
+	#[test]
+	fn query_ignores_invalid_pairs() {
+		let query = [
+			("class", "Z"),
+			("rank", ""),
+			("unknown", "A"),
+			("gender", "male"),
+		];
+		let filter = Filter::from_query(query);
+		assert_eq!(
+			filter,
+			Filter {
+				gender: Some(Gender::Male),
+				..Filter::default()
+			}
+		);
+	}
+
+	#[test]
+	fn min_bounds() {
+		let filter = Filter {
+			min_class: Some(Class::AA),
+			..Filter::default()
+		};
+		assert!(!filter.matches(&spirit(Some(Class::A), None)));
+		assert!(filter.matches(&spirit(Some(Class::AA), None)));
+		assert!(filter.matches(&spirit(Some(Class::SSS), None)));
+		assert!(!filter.matches(&spirit(None, None)));
+
+		let filter = Filter {
+			min_rank: Some(Class::S),
+			..Filter::default()
+		};
+		assert!(!filter.matches(&spirit(Some(Class::SSS), Some(Class::AAA))));
+		assert!(filter.matches(&spirit(None, Some(Class::S))));
+		assert!(!filter.matches(&spirit(None, None)));
+
+		assert!(Filter::default().matches(&spirit(None, None)));
+	}
+}
@@ -0,0 +1,130 @@
+//! Groupings of the list of spirits
+use crate::{Class, Element, FormCategory, Medium, Series, Spirit};
+use dal_spirits_proc::{Display_with_Serialize, EnumVariantIter, FromStr_with_Deserialize};
+use serde::{Deserialize, Serialize};
+
+const KEY_GROUP: &str = "group";
+
+/// Criterion by which spirits are grouped
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	FromStr_with_Deserialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+	#[default]
+	Series,
+	Element,
+	Medium,
+	Rank,
+	Form,
+}
+impl GroupBy {
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::Series => "Series",
+			Self::Element => "Element",
+			Self::Medium => "Medium",
+			Self::Rank => "Rank",
+			Self::Form => "Form category",
+		}
+	}
+
+	/// Get the groups, in display order
+	pub fn groups(&self) -> Vec<Group> {
+		match self {
+			Self::Series => Series::variants().into_iter().map(Group::Series).collect(),
+			Self::Element => Element::variants().into_iter().map(Group::Element).collect(),
+			Self::Medium => Medium::variants().into_iter().map(Group::Medium).collect(),
+			// Highest ranks first
+			Self::Rank => Class::variants()
+				.into_iter()
+				.rev()
+				.map(Some)
+				.chain([None])
+				.map(Group::Rank)
+				.collect(),
+			Self::Form => FormCategory::variants().into_iter().map(Group::Form).collect(),
+		}
+	}
+
+	/// Encode the criterion as key-value pairs, for the query string of a URL
+	///
+	/// The default criterion is left out.
+	pub fn to_query(&self) -> Vec<(&'static str, String)> {
+		if *self == Self::default() {
+			Vec::new()
+		} else {
+			vec![(KEY_GROUP, self.to_string())]
+		}
+	}
+
+	/// Decode the criterion from key-value pairs made by [`to_query`](Self::to_query)
+	///
+	/// Unknown keys and invalid values are ignored.
+	pub fn from_query<'q, I>(query: I) -> Self
+	where
+		I: IntoIterator<Item = (&'q str, &'q str)>,
+	{
+		query
+			.into_iter()
+			.filter(|(key, _)| *key == KEY_GROUP)
+			.find_map(|(_, value)| value.parse().ok())
+			.unwrap_or_default()
+	}
+}
+
+/// Group of spirits sharing a value, a spirit belonging to several groups if it has several values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Group {
+	Series(Series),
+	Element(Element),
+	Medium(Medium),
+	/// Rank tier, `None` gathering unranked spirits
+	Rank(Option<Class>),
+	Form(FormCategory),
+}
+impl Group {
+	/// Get an identifier of the group, unique among all groups
+	pub fn id(&self) -> String {
+		match self {
+			Self::Series(series) => format!("{series:?}"),
+			Self::Element(element) => format!("element-{element:?}"),
+			Self::Medium(medium) => format!("medium-{medium:?}"),
+			Self::Rank(Some(rank)) => format!("rank-{rank:?}"),
+			Self::Rank(None) => "rank-none".to_string(),
+			Self::Form(category) => format!("form-{category:?}"),
+		}
+	}
+
+	pub fn name(&self) -> String {
+		match self {
+			Self::Series(series) => series.to_string(),
+			Self::Element(element) => element.to_string(),
+			Self::Medium(medium) => medium.to_string(),
+			Self::Rank(Some(rank)) => format!("Rank {rank}"),
+			Self::Rank(None) => "Unranked".to_string(),
+			Self::Form(category) => category.to_string(),
+		}
+	}
+
+	pub fn contains(&self, spirit: &Spirit) -> bool {
+		match *self {
+			Self::Series(series) => spirit.series() == series,
+			Self::Element(element) => spirit.elements.contains(element),
+			Self::Medium(medium) => spirit.media.contains(medium),
+			Self::Rank(rank) => spirit.rank == rank,
+			Self::Form(category) => spirit.form_categories().contains(&category),
+		}
+	}
+}
@@ -0,0 +1,182 @@
+//! Export of birthdays to iCalendar (RFC 5545)
+use crate::{Date, Month, Spirit};
+use std::collections::HashSet;
+
+pub const MEDIA_TYPE: &str = "text/calendar";
+
+/// Escape a value of type `TEXT`
+fn escape(text: &str) -> String {
+	// CRLF and bare CR are line breaks too, which are escaped as `\n` alone
+	let text = text.replace("\r\n", "\n");
+	text.chars()
+		.fold(String::with_capacity(text.len()), |mut acc, c| {
+			match c {
+				'\\' | ';' | ',' => {
+					acc.push('\\');
+					acc.push(c);
+				}
+				'\n' | '\r' => acc.push_str("\\n"),
+				_ => acc.push(c),
+			}
+			acc
+		})
+}
+
+/// Append a content line, folded so that no line is longer than 75 octets
+fn push_line(ics: &mut String, line: &str) {
+	let mut len = 0;
+	for c in line.chars() {
+		if len + c.len_utf8() > 75 {
+			ics.push_str("\r\n ");
+			len = 1;
+		}
+		ics.push(c);
+		len += c.len_utf8();
+	}
+	ics.push_str("\r\n");
+}
+
+/// Build a calendar holding a yearly recurring event on the birthday of each character
+///
+/// Characters are identified by their [slug](Spirit::chara_slug), so only the first form of each one is used.
+/// `today` is the timestamp of the events.
+pub fn to_ics<'s, I>(spirits: I, today: Date) -> String
+where
+	I: IntoIterator<Item = &'s Spirit>,
+{
+	let mut ics = String::new();
+	push_line(&mut ics, "BEGIN:VCALENDAR");
+	push_line(&mut ics, "VERSION:2.0");
+	push_line(
+		&mut ics,
+		&format!(
+			"PRODID:-//{name}//{name} {version}//EN",
+			name = env!("CARGO_PKG_NAME"),
+			version = env!("CARGO_PKG_VERSION")
+		),
+	);
+	push_line(&mut ics, "CALSCALE:GREGORIAN");
+
+	let mut charas = HashSet::new();
+	for spirit in spirits {
+		let Some(birthdate) = spirit.birthdate else {
+			continue;
+		};
+		if !charas.insert(spirit.chara_slug()) {
+			continue;
+		}
+		let name = match spirit.lastname {
+			Some(ref lastname) => format!("{} {lastname}", spirit.firstname),
+			None => spirit.firstname.clone(),
+		};
+		let (month, day) = (birthdate.month() as u8 + 1, birthdate.day());
+
+		push_line(&mut ics, "BEGIN:VEVENT");
+		push_line(
+			&mut ics,
+			&format!(
+				"UID:{slug}@{name}",
+				slug = spirit.chara_slug(),
+				name = env!("CARGO_PKG_NAME")
+			),
+		);
+		push_line(
+			&mut ics,
+			&format!(
+				"DTSTAMP:{year:04}{month:02}{day:02}T000000Z",
+				year = today.year(),
+				month = today.month() as u8 + 1,
+				day = today.day()
+			),
+		);
+		// 2000 is a leap year, so that February 29th is a valid start
+		push_line(
+			&mut ics,
+			&format!("DTSTART;VALUE=DATE:2000{month:02}{day:02}"),
+		);
+		if birthdate.month() == Month::February && day == 29 {
+			// Fall back to February 28th in common years
+			push_line(&mut ics, "RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1");
+		} else {
+			push_line(&mut ics, "RRULE:FREQ=YEARLY");
+		}
+		push_line(
+			&mut ics,
+			&format!("SUMMARY:{}", escape(&format!("{name}'s birthday"))),
+		);
+		push_line(&mut ics, "TRANSP:TRANSPARENT");
+		push_line(&mut ics, "END:VEVENT");
+	}
+
+	push_line(&mut ics, "END:VCALENDAR");
+	ics
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{escape, to_ics};
+	use crate::{test_utils::spirit, Date, Month};
+	use serde_json::json;
+
+	fn today() -> Date {
+		Date::new(2024, Month::April, 10).unwrap()
+	}
+
+	#[test]
+	fn escape_text() {
+		assert_eq!(escape("Tohka"), "Tohka");
+		assert_eq!(escape(r"a\b;c,d"), r"a\\b\;c\,d");
+		assert_eq!(escape("a\nb"), r"a\nb");
+		assert_eq!(escape("a\r\nb\rc"), r"a\nb\nc");
+	}
+
+	#[test]
+	fn leap_day() {
+		let spirits = [spirit(json!({ "birthdate": "February 29" }))];
+		let ics = to_ics(&spirits, today());
+		assert!(ics.contains("\r\nDTSTAMP:20240410T000000Z\r\n"));
+		assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20000229\r\n"));
+		assert!(ics.contains("\r\nRRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1\r\n"));
+	}
+
+	#[test]
+	fn one_event_per_character() {
+		let spirits = [
+			spirit(json!({ "birthdate": "April 10" })),
+			spirit(json!({
+				"form": "Inverse",
+				"form_slug": "Inverse",
+				"birthdate": "April 10",
+			})),
+			spirit(json!({
+				"firstname": "Kotori",
+				"chara_slug": "Kotori",
+				"birthdate": "August 3",
+			})),
+			spirit(json!({ "firstname": "Origami", "chara_slug": "Origami" })),
+		];
+		let ics = to_ics(&spirits, today());
+		assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+		assert_eq!(ics.matches("\r\nUID:Tohka@").count(), 1);
+		assert_eq!(ics.matches("\r\nUID:Kotori@").count(), 1);
+		assert!(ics.contains("\r\nRRULE:FREQ=YEARLY\r\n"));
+	}
+
+	#[test]
+	fn folded_lines() {
+		let firstname = "東".repeat(40);
+		let spirits = [spirit(json!({
+			"firstname": firstname,
+			"birthdate": "April 10",
+		}))];
+		let ics = to_ics(&spirits, today());
+		assert!(ics.ends_with("\r\n"));
+		for line in ics.trim_end_matches("\r\n").split("\r\n") {
+			assert!(line.len() <= 75, "{line:?}");
+		}
+		assert!(ics.contains("\r\n 東"));
+		assert!(ics
+			.replace("\r\n ", "")
+			.contains(&format!("\r\nSUMMARY:{firstname}'s birthday\r\n")));
+	}
+}
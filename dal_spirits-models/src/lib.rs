@@ -0,0 +1,138 @@
+//! Data model of the characters referenced by the website
+//!
+//! This crate is platform-independent: it is shared by the web application,
+//! the dataset linter and any other tool that needs to read `spirits.csv`.
+#![forbid(unsafe_code)]
+#![deny(unused_must_use)]
+
+#[cfg(feature = "binary")]
+pub mod binary;
+mod error;
+pub mod filter;
+pub mod group;
+pub mod ics;
+#[cfg(feature = "csv")]
+pub mod lint;
+mod models;
+pub mod search;
+#[cfg(feature = "csv")]
+pub mod ser;
+pub mod slug;
+pub mod sort;
+pub mod stats;
+#[cfg(test)]
+mod test_utils;
+mod utils;
+
+pub use error::{BirthdateError, Error, FieldError};
+pub use models::*;
+pub use utils::{ordinal_number, Alias, CharFlags};
+
+/// Formats in which the dataset can be exchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+	/// `text/csv`, see [`csv_deserializer`]
+	Csv,
+	/// `application/json`, an array of [`Spirit`] objects
+	Json,
+}
+impl Format {
+	#[inline]
+	pub const fn media_type(&self) -> &'static str {
+		match self {
+			Self::Csv => "text/csv",
+			Self::Json => "application/json",
+		}
+	}
+
+	/// Get the format matching a `Content-Type` header value
+	pub fn from_content_type(content_type: &str) -> Option<Self> {
+		let essence = content_type.split(';').next()?.trim().to_ascii_lowercase();
+		match essence.as_str() {
+			"text/csv" => Some(Self::Csv),
+			"application/json" | "text/json" => Some(Self::Json),
+			_ if essence.ends_with("+json") => Some(Self::Json),
+			_ => None,
+		}
+	}
+
+	/// Get the format matching the extension of a file name or URL
+	pub fn from_path(path: &str) -> Option<Self> {
+		let path = path.split(['?', '#']).next()?;
+		let (_, ext) = path.rsplit_once('.')?;
+		match ext.to_ascii_lowercase().as_str() {
+			"csv" => Some(Self::Csv),
+			"json" => Some(Self::Json),
+			_ => None,
+		}
+	}
+}
+
+/// Build a deserializer for the CSV dialect of `spirits.csv`
+///
+/// Lists are separated by `;` and the fields of their items by `:`.
+#[cfg(feature = "csv")]
+pub fn csv_deserializer() -> serdenom_csv::de::DeserializerBuilder {
+	use serdenom_csv::de::{DeserializerBuilder, Separators};
+
+	DeserializerBuilder::default().separators(
+		ser::SEPARATORS.map(|(record_sep, field_sep)| Separators(record_sep, field_sep)),
+	)
+}
+
+/// Deserialize the dataset from its CSV form
+///
+/// On failure, the dataset is [linted](lint::lint) to report every faulty field.
+#[cfg(feature = "csv")]
+pub fn from_csv(csv: &str) -> Result<Vec<Spirit>, Error> {
+	let res: Result<Vec<Spirit>, _> = csv_deserializer().deserialize(csv);
+	match res {
+		Ok(spirits) => {
+			let rows = lint::split_records(csv)
+				.map(|(_, records)| records.map(|(row, _)| row).collect::<Vec<_>>())
+				.unwrap_or_default();
+			// Blank lines hold no records, but are still counted
+			check_slugs(spirits, |idx| rows.get(idx).copied().unwrap_or(idx + 2))
+		}
+		Err(err) => {
+			let errors = lint::lint(csv)
+				.diagnostics
+				.into_iter()
+				.filter(|diag| diag.severity == lint::Severity::Error)
+				.map(FieldError::from)
+				.collect::<Vec<_>>();
+			Err(if errors.is_empty() {
+				Error::Syntax(err.to_string())
+			} else {
+				Error::Validation(errors)
+			})
+		}
+	}
+}
+
+/// Deserialize the dataset from its JSON form
+#[cfg(feature = "json")]
+pub fn from_json(json: &str) -> Result<Vec<Spirit>, Error> {
+	serde_json::from_str(json)
+		.map_err(|err| Error::Syntax(err.to_string()))
+		.and_then(|spirits| check_slugs(spirits, |idx| idx + 1))
+}
+
+/// Reject spirits whose slugs cannot identify them, see [`slug::check`]
+#[cfg(any(feature = "csv", feature = "json"))]
+fn check_slugs<F: Fn(usize) -> usize>(spirits: Vec<Spirit>, row: F) -> Result<Vec<Spirit>, Error> {
+	let errors = slug::check(&spirits)
+		.into_iter()
+		.map(|err| FieldError {
+			row: row(err.index),
+			name: Some(spirits[err.index].firstname.clone()),
+			column: Some(err.field.to_string()),
+			message: err.message,
+		})
+		.collect::<Vec<_>>();
+	if errors.is_empty() {
+		Ok(spirits)
+	} else {
+		Err(Error::Validation(errors))
+	}
+}
@@ -0,0 +1,359 @@
+//! Validation of the `spirits.csv` dataset
+use crate::{ser::SEPARATORS, FieldError, Spirit};
+use std::fmt::{self, Display, Formatter};
+
+/// Separator of the records, which the dialect never escapes
+const RECORD_SEP: char = SEPARATORS[0].0;
+/// Separator of the fields of a record, which the dialect never escapes
+const FIELD_SEP: char = SEPARATORS[0].1;
+
+/// Write the fields of a record back to a line
+#[inline]
+fn join_fields(fields: &[&str]) -> String {
+	fields.join(&FIELD_SEP.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+	Error,
+	Warning,
+}
+impl Display for Severity {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Error => write!(f, "error"),
+			Self::Warning => write!(f, "warning"),
+		}
+	}
+}
+
+/// Issue found in the dataset
+#[derive(Debug)]
+pub struct Diagnostic {
+	pub severity: Severity,
+	/// Line number in the file, starting at 1 with the header
+	pub row: usize,
+	/// First name of the character, if it could be read
+	pub name: Option<String>,
+	/// Column number, starting at 1, and name of the field
+	pub column: Option<(usize, String)>,
+	pub message: String,
+}
+impl Display for Diagnostic {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.column {
+			Some((col, ref field)) => write!(
+				f,
+				"{row}:{col}: {severity} in field \"{field}\"",
+				row = self.row,
+				severity = self.severity,
+			)?,
+			None => write!(
+				f,
+				"{row}: {severity}",
+				row = self.row,
+				severity = self.severity,
+			)?,
+		}
+		if let Some(ref name) = self.name {
+			write!(f, " of {name}")?;
+		}
+		write!(f, ": {msg}", msg = self.message)
+	}
+}
+impl From<Diagnostic> for FieldError {
+	#[inline]
+	fn from(diag: Diagnostic) -> Self {
+		Self {
+			row: diag.row,
+			name: diag.name,
+			column: diag.column.map(|(_, field)| field),
+			message: diag.message,
+		}
+	}
+}
+
+struct Linter<'h> {
+	columns: Vec<&'h str>,
+	diagnostics: Vec<Diagnostic>,
+}
+impl<'h> Linter<'h> {
+	fn new(header: &'h str) -> Self {
+		Self {
+			columns: header.split(FIELD_SEP).collect(),
+			diagnostics: Vec::new(),
+		}
+	}
+
+	fn column(&self, field: &str) -> Option<(usize, String)> {
+		self.columns
+			.iter()
+			.position(|column| *column == field)
+			.map(|idx| (idx + 1, field.to_string()))
+	}
+
+	fn report(
+		&mut self,
+		severity: Severity,
+		row: usize,
+		name: Option<&str>,
+		field: Option<&str>,
+		message: String,
+	) {
+		let column = field.and_then(|field| self.column(field));
+		self.diagnostics.push(Diagnostic {
+			severity,
+			row,
+			name: name.map(str::to_string),
+			column,
+			message,
+		});
+	}
+
+	/// Deserialize a single line with the same settings as the web application
+	fn deserialize(&self, line: &str) -> Result<Spirit, String> {
+		let csv = format!(
+			"{header}{RECORD_SEP}{line}",
+			header = join_fields(&self.columns)
+		);
+		let res: Result<Vec<Spirit>, _> = crate::csv_deserializer().deserialize(&csv);
+		res.map_err(|err| err.to_string())?
+			.into_iter()
+			.next()
+			.ok_or_else(|| "no record found".to_string())
+	}
+
+	/// Parse a line, pinpointing the faulty fields on failure
+	fn parse_row(&mut self, row: usize, line: &str) -> Option<Spirit> {
+		let err = match self.deserialize(line) {
+			Ok(spirit) => return Some(spirit),
+			Err(err) => err,
+		};
+
+		let fields = line.split(FIELD_SEP).collect::<Vec<_>>();
+		let name = self
+			.columns
+			.iter()
+			.position(|column| *column == "firstname")
+			.and_then(|idx| fields.get(idx).copied())
+			.filter(|name| !name.is_empty());
+		if fields.len() != self.columns.len() {
+			self.report(
+				Severity::Error,
+				row,
+				name,
+				None,
+				format!(
+					"expected {expected} fields, found {found}",
+					expected = self.columns.len(),
+					found = fields.len()
+				),
+			);
+			return None;
+		}
+
+		// Probe each field alone in an otherwise empty record
+		let blank = vec![""; self.columns.len()];
+		let mut found = false;
+		if self.deserialize(&join_fields(&blank)).is_ok() {
+			for (idx, value) in fields.iter().enumerate() {
+				if value.is_empty() {
+					continue;
+				}
+				let mut probe = blank.clone();
+				probe[idx] = value;
+				if let Err(err) = self.deserialize(&join_fields(&probe)) {
+					found = true;
+					let field = self.columns[idx];
+					self.report(
+						Severity::Error,
+						row,
+						name,
+						Some(field),
+						format!("invalid value \"{value}\": {err}"),
+					);
+				}
+			}
+		}
+		if !found {
+			self.report(Severity::Error, row, name, None, err);
+		}
+		None
+	}
+
+	/// Check that writing a record back to CSV yields the same record
+	fn check_round_trip(&mut self, spirits: &[(usize, Spirit)]) {
+		for (row, spirit) in spirits {
+			let res = crate::ser::to_csv(std::slice::from_ref(spirit))
+				.map_err(|err| err.to_string())
+				.and_then(|csv| {
+					let res: Result<Vec<Spirit>, _> = crate::csv_deserializer().deserialize(&csv);
+					res.map_err(|err| err.to_string())
+				});
+			match res {
+				Ok(written) if written.len() == 1 && written[0] == *spirit => {}
+				Ok(written) => self.report(
+					Severity::Error,
+					*row,
+					Some(&spirit.firstname),
+					None,
+					format!("record is read back as {written:?}"),
+				),
+				Err(err) => self.report(
+					Severity::Error,
+					*row,
+					Some(&spirit.firstname),
+					None,
+					format!("record cannot be written back: {err}"),
+				),
+			}
+		}
+	}
+
+	fn check(&mut self, spirits: &[(usize, Spirit)]) {
+		use std::collections::HashMap;
+
+		for err in crate::slug::check(spirits.iter().map(|(_, spirit)| spirit)) {
+			let (row, ref spirit) = spirits[err.index];
+			let mut message = err.message;
+			if err.field == "form_slug" && spirit.form_slug().is_empty() {
+				message += &format!(" (suggested: {:?})", spirit.form_url());
+			}
+			self.report(
+				Severity::Error,
+				row,
+				Some(&spirit.firstname),
+				Some(err.field),
+				message,
+			);
+		}
+
+		let mut charas: HashMap<&str, &(usize, Spirit)> = HashMap::new();
+		for entry in spirits {
+			let (row, ref spirit) = *entry;
+
+			match charas.get(spirit.chara_slug()) {
+				Some((first_row, first)) => {
+					macro_rules! compare {
+						($severity:expr, $field:literal, $get:expr) => {
+							let (expected, found) = ($get(first), $get(spirit));
+							if expected != found {
+								self.report(
+									$severity,
+									row,
+									Some(&spirit.firstname),
+									Some($field),
+									format!(
+										"{found:?} differs from {expected:?} at line {first_row}"
+									),
+								);
+							}
+						};
+					}
+					compare!(Severity::Error, "lastname", |s: &Spirit| s.lastname.clone());
+					compare!(Severity::Error, "gender", |s: &Spirit| s.gender());
+					compare!(Severity::Error, "series", |s: &Spirit| s.series());
+					compare!(Severity::Error, "birthdate", |s: &Spirit| s.birthdate);
+					// Some forms legitimately change the body of the character
+					compare!(Severity::Warning, "height", |s: &Spirit| s.height);
+					compare!(Severity::Warning, "weight", |s: &Spirit| s.weight);
+					compare!(Severity::Warning, "bust", |s: &Spirit| s.bust);
+					compare!(Severity::Warning, "waist", |s: &Spirit| s.waist);
+					compare!(Severity::Warning, "hips", |s: &Spirit| s.hips);
+				}
+				None => {
+					charas.insert(spirit.chara_slug(), entry);
+				}
+			}
+		}
+	}
+}
+
+/// Outcome of [`lint`]
+#[derive(Debug)]
+pub struct Report {
+	/// Spirits that could be deserialized, in file order
+	pub spirits: Vec<Spirit>,
+	/// Issues sorted by line
+	pub diagnostics: Vec<Diagnostic>,
+}
+impl Report {
+	#[inline]
+	pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+		self.diagnostics
+			.iter()
+			.filter(|diag| diag.severity == Severity::Error)
+	}
+
+	#[inline]
+	pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+		self.diagnostics
+			.iter()
+			.filter(|diag| diag.severity == Severity::Warning)
+	}
+
+	#[inline]
+	pub fn has_errors(&self) -> bool {
+		self.errors().next().is_some()
+	}
+}
+
+/// Split some CSV into its header and its records, numbered from 1 with the header
+///
+/// Blank lines are skipped like the deserializer does, but still counted.
+pub(crate) fn split_records(csv: &str) -> Option<(&str, impl Iterator<Item = (usize, &str)>)> {
+	let mut lines = csv.lines().enumerate().map(|(idx, line)| (idx + 1, line));
+	let (_, header) = lines.next()?;
+	Some((header, lines.filter(|(_, line)| !line.is_empty())))
+}
+
+/// Deserialize the dataset and check its consistency
+///
+/// Every faulty record is reported, rather than only the first one.
+/// Records are also written back with [`ser::to_csv`](crate::ser::to_csv)
+/// to make sure that no information is lost.
+pub fn lint(csv: &str) -> Report {
+	let Some((header, records)) = split_records(csv) else {
+		return Report {
+			spirits: Vec::new(),
+			diagnostics: vec![Diagnostic {
+				severity: Severity::Error,
+				row: 1,
+				name: None,
+				column: None,
+				message: "missing header".to_string(),
+			}],
+		};
+	};
+	let mut linter = Linter::new(header);
+	let spirits = records
+		.filter_map(|(row, line)| linter.parse_row(row, line).map(|spirit| (row, spirit)))
+		.collect::<Vec<_>>();
+	linter.check(&spirits);
+	linter.check_round_trip(&spirits);
+
+	let mut diagnostics = linter.diagnostics;
+	diagnostics.sort_by_key(|diag| diag.row);
+	Report {
+		spirits: spirits.into_iter().map(|(_, spirit)| spirit).collect(),
+		diagnostics,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::split_records;
+
+	#[test]
+	fn record_rows() {
+		let (header, records) =
+			split_records("firstname,form\nTohka,Spirit\n\nKotori,Spirit\n").unwrap();
+		assert_eq!(header, "firstname,form");
+		assert_eq!(
+			records.collect::<Vec<_>>(),
+			[(2, "Tohka,Spirit"), (4, "Kotori,Spirit")]
+		);
+
+		assert!(split_records("").is_none());
+	}
+}
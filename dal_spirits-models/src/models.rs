@@ -0,0 +1,1195 @@
+use crate::{error::BirthdateError, utils::CharFlags};
+use dal_spirits_proc::{
+	Display_with_Serialize, EnumAlias, EnumVariantIter, FromStr_with_Deserialize,
+	TryFrom_with_FromStr,
+};
+use either::Either;
+use enumflags2::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+	cmp::Ordering,
+	fmt::{self, Display, Formatter},
+	hash::Hash,
+	str::FromStr,
+};
+
+const REPO_ROOT: &str = "https://raw.githubusercontent.com/n0k0m3/DateALiveData/master/res/basic";
+const FANDOM_ROOT: &str = "https://date-a-live.fandom.com/wiki";
+const WIKI_ROOT: &str = "https://wikipedia.org/wiki";
+
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	PartialOrd,
+	Ord,
+	Deserialize,
+	Serialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+pub enum Series {
+	#[serde(rename = "Date A Live", alias = "")]
+	DateALive,
+	#[serde(rename = "Date A Bullet")]
+	DateABullet,
+	Index,
+	Neptunia,
+	DanMachi,
+	Bofuri,
+}
+impl Default for Series {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::DateALive
+	}
+}
+impl Series {
+	#[inline(always)]
+	pub fn is_collab(&self) -> bool {
+		*self != Self::DateALive && *self != Self::DateABullet
+	}
+}
+
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	FromStr_with_Deserialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Gender {
+	#[serde(alias = "")]
+	Female,
+	Male,
+}
+impl Default for Gender {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::Female
+	}
+}
+
+#[bitflags]
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Hash,
+	Deserialize,
+	Serialize,
+	Display_with_Serialize,
+	EnumAlias,
+	EnumVariantIter,
+)]
+#[repr(u8)]
+pub enum Medium {
+	#[serde(alias = "l", rename = "Main Light Novels")]
+	MainLightNovels,
+	#[serde(alias = "s", rename = "Spin-off Light Novels")]
+	SpinoffLightNovels,
+	#[serde(alias = "a", rename = "Anime")]
+	Anime,
+	#[serde(alias = "m")]
+	Movie,
+	#[serde(alias = "v", rename = "Visual Novels")]
+	VisualNovels,
+	#[serde(alias = "g")]
+	Gacha,
+}
+impl Default for Medium {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::MainLightNovels
+	}
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Display_with_Serialize)]
+pub enum Codename {
+	Codename,
+	#[serde(rename = "Goddess Title")]
+	GoddessTitle,
+	Username,
+}
+impl Default for Codename {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::Codename
+	}
+}
+impl From<Series> for Codename {
+	#[inline]
+	fn from(series: Series) -> Self {
+		match series {
+			Series::Neptunia => Self::GoddessTitle,
+			Series::Bofuri => Self::Username,
+			_ => Self::default(),
+		}
+	}
+}
+
+pub trait Attribute {
+	type Wording: ToString;
+
+	fn wording(&self) -> &Self::Wording;
+	fn name(&self) -> &str;
+	fn subtitle(&self) -> Option<&str>;
+}
+
+impl<W: ToString> Attribute for (W, &str) {
+	type Wording = W;
+
+	#[inline(always)]
+	fn wording(&self) -> &Self::Wording {
+		&self.0
+	}
+
+	#[inline(always)]
+	fn name(&self) -> &str {
+		self.1
+	}
+
+	#[inline(always)]
+	fn subtitle(&self) -> Option<&str> {
+		None
+	}
+}
+
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	Display_with_Serialize,
+	EnumAlias,
+)]
+pub enum SephiraWording {
+	#[serde(alias = "s")]
+	Sephira,
+	#[serde(alias = "q")]
+	Qlipha,
+	#[serde(alias = "l")]
+	Land,
+}
+impl Default for SephiraWording {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::Sephira
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Sephira {
+	#[serde(serialize_with = "crate::utils::serialize_alias")]
+	wording: SephiraWording,
+	name: String,
+	subtitle: Option<String>,
+	rest0: Option<String>,
+	rest1: Option<String>,
+}
+impl Attribute for Sephira {
+	type Wording = SephiraWording;
+
+	#[inline(always)]
+	fn wording(&self) -> &Self::Wording {
+		&self.wording
+	}
+
+	#[inline(always)]
+	fn name(&self) -> &str {
+		&self.name
+	}
+
+	#[inline]
+	fn subtitle(&self) -> Option<&str> {
+		self.subtitle.as_deref()
+	}
+}
+impl Sephira {
+	#[inline]
+	pub fn link(&self) -> String {
+		match self.wording {
+			SephiraWording::Sephira => format!(
+				"{WIKI_ROOT}/{path}",
+				path = self.rest0.as_deref().unwrap_or(&self.name)
+			),
+			SephiraWording::Qlipha => format!(
+				"{WIKI_ROOT}/{path}",
+				path = self.rest1.as_deref().unwrap_or(&self.name)
+			),
+			SephiraWording::Land => {
+				format!("https://neptunia.fandom.com/wiki/{path}", path = self.name)
+			}
+		}
+	}
+
+	/// Get the name of the Sephira of the Tree of Life, such as `Malkuth`
+	///
+	/// Qliphoth point back to their Sephira, and lands have none.
+	#[inline]
+	pub fn sephira(&self) -> Option<&str> {
+		match self.wording {
+			SephiraWording::Sephira => Some(&self.name),
+			SephiraWording::Qlipha => self.rest0.as_deref(),
+			SephiraWording::Land => None,
+		}
+	}
+
+	#[inline]
+	pub fn icon(&self) -> Option<String> {
+		match self.wording {
+			SephiraWording::Sephira => Some(format!(
+				"{REPO_ROOT}/icon/equipType/{name}.png",
+				name = self.name
+			)),
+			SephiraWording::Qlipha => self
+				.rest0
+				.as_deref()
+				.map(|r| format!("{REPO_ROOT}/icon/equipType/{r}.png")),
+			SephiraWording::Land => self.rest0.as_deref().map(|r| {
+				format!(
+					"https://static.wikia.nocookie.net/neptunia/images/{r}/{name}_Logo.png",
+					name = self.name
+				)
+			}),
+		}
+	}
+
+	#[inline]
+	pub fn guardian(&self) -> Option<String> {
+		match self.wording {
+			SephiraWording::Sephira => match self.name() {
+				"Chesed" => Some("40210_D1"),
+				"Geburah" => Some("40105_weixiao"),
+				"Tiphareth" => Some("40106_weixiao"),
+				_ => None,
+			}
+			.map(|s| format!("{REPO_ROOT}/icon/battleDialog/btlPortrait_{s}.png")),
+			SephiraWording::Qlipha => self
+				.rest0
+				.as_deref()
+				.and_then(|r| match r {
+					"Kether" => Some("40201_a1"),
+					"Chokhmah" => Some("40202_a1"),
+					"Binah" => Some("40203_a1"),
+					"Chesed" => Some("40209_c1"),
+					"Geburah" => Some("31001L_d1"),
+					"Tiphareth" => Some("31101L_a1"),
+					_ => None,
+				})
+				.map(|s| format!("{REPO_ROOT}/icon/battleDialog/btlPortrait_{s}.png")),
+			SephiraWording::Land => match self.name() {
+				"Planeptune" => Some("40214_b1"),
+				"Lastation" => Some("40220_weixiao"),
+				"Lowee" => Some("40216_a1"),
+				"Leanbox" => Some("40218_daiji"),
+				_ => None,
+			}
+			.map(|s| format!("{REPO_ROOT}/icon/battleDialog/btlPortrait_{s}.png")),
+		}
+	}
+}
+
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	Display_with_Serialize,
+	EnumAlias,
+)]
+pub enum AngelWording {
+	#[serde(alias = "a")]
+	Angel,
+	#[serde(alias = "w")]
+	Weapon,
+	#[serde(alias = "d")]
+	Demon,
+	#[serde(rename = "Unsigned Angel", alias = "u")]
+	UnsignedAngel,
+	#[serde(rename = "Esper Power", alias = "e")]
+	EsperPower,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Angel {
+	#[serde(serialize_with = "crate::utils::serialize_alias")]
+	wording: AngelWording,
+	name: String,
+	subtitle: Option<String>,
+	rest0: Option<String>,
+}
+impl Attribute for Angel {
+	type Wording = AngelWording;
+
+	#[inline(always)]
+	fn wording(&self) -> &Self::Wording {
+		&self.wording
+	}
+
+	#[inline(always)]
+	fn name(&self) -> &str {
+		&self.name
+	}
+
+	#[inline]
+	fn subtitle(&self) -> Option<&str> {
+		self.subtitle.as_deref()
+	}
+}
+impl Angel {
+	#[inline]
+	pub fn link(&self) -> Option<String> {
+		(self.wording == AngelWording::Angel || self.wording == AngelWording::Demon).then(|| {
+			format!(
+				"{WIKI_ROOT}/{page}",
+				page = self
+					.rest0
+					.as_deref()
+					.and_then(|s| (!s.is_empty()).then_some(s))
+					.unwrap_or(&self.name)
+			)
+		})
+	}
+}
+
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	Display_with_Serialize,
+	EnumAlias,
+)]
+pub enum AstralDressWording {
+	#[serde(rename = "Astral Dress", alias = "ad")]
+	AstralDress,
+	#[serde(rename = "CR-Unit", alias = "cr")]
+	CrUnit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct AstralDress {
+	#[serde(serialize_with = "crate::utils::serialize_alias")]
+	wording: AstralDressWording,
+	name: String,
+	#[serde(with = "crate::utils::serde_either_optional")]
+	number: Option<Either<u16, String>>,
+}
+impl Attribute for AstralDress {
+	type Wording = AstralDressWording;
+
+	#[inline(always)]
+	fn wording(&self) -> &Self::Wording {
+		&self.wording
+	}
+
+	#[inline(always)]
+	fn name(&self) -> &str {
+		&self.name
+	}
+
+	#[inline(always)]
+	fn subtitle(&self) -> Option<&str> {
+		None
+	}
+}
+impl AstralDress {
+	#[inline]
+	pub fn subtitle(&self, spirit: &Spirit) -> Option<String> {
+		self.number.as_ref().map(|n| {
+			let n = match n {
+				Either::Left(num) => crate::utils::ordinal_number(*num),
+				Either::Right(txt) => txt.to_string(),
+			};
+			match spirit.form.as_str() {
+				"Pseudo-Spirit" => format!("Incantation Spirit Dress, {n}"),
+				"Quasi-Spirit" => format!("Stranded Spirit Dress, {n}",),
+				_ => format!("Spirit Dress of God's Authority, {n}",),
+			}
+		})
+	}
+
+	#[inline]
+	pub fn link(&self, spirit: &Spirit) -> Option<String> {
+		(self.wording == AstralDressWording::AstralDress && spirit.form != "Quasi-Spirit")
+			.then(|| format!("{WIKI_ROOT}/Names_of_God_in_Judaism"))
+	}
+}
+
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	PartialOrd,
+	Ord,
+	Deserialize,
+	Serialize,
+	FromStr_with_Deserialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+pub enum Month {
+	January,
+	February,
+	March,
+	April,
+	May,
+	June,
+	July,
+	August,
+	September,
+	October,
+	November,
+	December,
+}
+impl Month {
+	/// Get the number of days in the month, including February 29th
+	#[inline]
+	pub const fn days(&self) -> u8 {
+		match self {
+			Self::February => 29,
+			Self::April | Self::June | Self::September | Self::November => 30,
+			_ => 31,
+		}
+	}
+
+	/// Get the number of days in the month of the given year
+	#[inline]
+	pub const fn days_in(&self, year: i32) -> u8 {
+		match self {
+			Self::February if !is_leap_year(year) => 28,
+			_ => self.days(),
+		}
+	}
+
+	/// Get the month from its number, starting at 1 with January
+	#[inline]
+	pub fn from_number(number: u8) -> Option<Self> {
+		Self::variants().get(usize::from(number).checked_sub(1)?).copied()
+	}
+}
+
+/// Check whether the year has a February 29th in the Gregorian calendar
+#[inline]
+pub const fn is_leap_year(year: i32) -> bool {
+	(year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Day of the Gregorian calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+	year: i32,
+	month: Month,
+	day: u8,
+}
+impl Date {
+	/// Make a date, checking that the day exists in the month of that year
+	#[inline]
+	pub fn new(year: i32, month: Month, day: u8) -> Option<Self> {
+		(1..=month.days_in(year)).contains(&day).then_some(Self { year, month, day })
+	}
+
+	#[inline(always)]
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	#[inline(always)]
+	pub fn month(&self) -> Month {
+		self.month
+	}
+
+	#[inline(always)]
+	pub fn day(&self) -> u8 {
+		self.day
+	}
+
+	/// Get the number of the day in its year, starting at 1 with January 1st
+	pub fn day_of_year(&self) -> u16 {
+		Month::variants()
+			.into_iter()
+			.take_while(|month| *month != self.month)
+			.map(|month| u16::from(month.days_in(self.year)))
+			.sum::<u16>()
+			+ u16::from(self.day)
+	}
+
+	#[inline]
+	fn days_in_year(year: i32) -> u16 {
+		if is_leap_year(year) {
+			366
+		} else {
+			365
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, TryFrom_with_FromStr)]
+pub struct Birthdate(Month, u8);
+impl Serialize for Birthdate {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		if serializer.is_human_readable() {
+			serializer.collect_str(&format_args!("{month} {day}", month = self.0, day = self.1))
+		} else {
+			(self.0, self.1).serialize(serializer)
+		}
+	}
+}
+impl<'de> Deserialize<'de> for Birthdate {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		use serde::de::Error;
+
+		if deserializer.is_human_readable() {
+			String::deserialize(deserializer)?
+				.parse()
+				.map_err(D::Error::custom)
+		} else {
+			let (month, day) = <(Month, u8)>::deserialize(deserializer)?;
+			Self::new(month, day).map_err(D::Error::custom)
+		}
+	}
+}
+impl Birthdate {
+	/// Make a birthdate, checking that the day exists in the month
+	///
+	/// February 29th is allowed.
+	#[inline]
+	pub fn new(month: Month, day: u8) -> Result<Self, BirthdateError> {
+		let birthdate = Self(month, day);
+		if birthdate.is_valid() {
+			Ok(birthdate)
+		} else {
+			Err(BirthdateError::NoSuchDay(month, day))
+		}
+	}
+
+	#[inline(always)]
+	pub fn month(&self) -> Month {
+		self.0
+	}
+
+	#[inline(always)]
+	pub fn day(&self) -> u8 {
+		self.1
+	}
+
+	/// Check that the day exists in the month
+	#[inline]
+	pub fn is_valid(&self) -> bool {
+		(1..=self.0.days()).contains(&self.1)
+	}
+
+	/// Get the number of the day in a leap year, starting at 1 with January 1st
+	///
+	/// This is consistent with the ordering of birthdates.
+	#[inline]
+	pub fn ordinal(&self) -> u16 {
+		// 2000 is a leap year
+		self.date_in(2000).day_of_year()
+	}
+
+	/// Get the day on which the birthday falls in the given year
+	///
+	/// February 29th falls on February 28th in common years.
+	#[inline]
+	pub fn date_in(&self, year: i32) -> Date {
+		Date {
+			year,
+			month: self.0,
+			day: self.1.min(self.0.days_in(year)),
+		}
+	}
+
+	/// Get the number of days from `today` until the next birthday, which is 0 on the birthday itself
+	pub fn days_until(&self, today: Date) -> u16 {
+		let (today_n, this_year_n) = (today.day_of_year(), self.date_in(today.year).day_of_year());
+		if this_year_n >= today_n {
+			this_year_n - today_n
+		} else {
+			Date::days_in_year(today.year) - today_n
+				+ self.date_in(today.year + 1).day_of_year()
+		}
+	}
+
+	#[inline]
+	pub fn is_today(&self, today: Date) -> bool {
+		self.date_in(today.year) == today
+	}
+}
+impl FromStr for Birthdate {
+	type Err = BirthdateError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		use once_cell::sync::Lazy;
+		use regex::Regex;
+
+		static RE: Lazy<Regex> =
+			Lazy::new(|| Regex::new(r"(?P<month>\w+)\s+(?P<day>\d{1,2})").unwrap());
+
+		let caps = RE.captures(s).ok_or(BirthdateError::Format)?;
+		let month = caps.name("month").unwrap().as_str();
+		let month = month
+			.parse()
+			.map_err(|_err| BirthdateError::InvalidMonth(month.to_string()))?;
+		let day = caps.name("day").unwrap().as_str();
+		let day: u8 = day
+			.parse()
+			.map_err(|_err| BirthdateError::InvalidDay(day.to_string()))?;
+		Birthdate::new(month, day)
+	}
+}
+impl PartialOrd for Birthdate {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		self.0.partial_cmp(&other.0).and_then(|ord| {
+			if ord == Ordering::Equal {
+				self.1.partial_cmp(&other.1)
+			} else {
+				Some(ord)
+			}
+		})
+	}
+}
+impl Ord for Birthdate {
+	fn cmp(&self, other: &Self) -> Ordering {
+		let ord = self.0.cmp(&other.0);
+		if ord == Ordering::Equal {
+			self.1.cmp(&other.1)
+		} else {
+			ord
+		}
+	}
+}
+impl Display for Birthdate {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{month} {n}",
+			month = self.0,
+			n = crate::utils::ordinal_number(self.1),
+		)
+	}
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	PartialOrd,
+	Ord,
+	Deserialize,
+	Serialize,
+	FromStr_with_Deserialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+pub enum Class {
+	D,
+	C,
+	B,
+	A,
+	AA,
+	AAA,
+	S,
+	SS,
+	SSS,
+	Ex,
+}
+impl Class {
+	pub fn icon(&self) -> (String, u8) {
+		let (id, rep) = match self {
+			Self::D => ("d", 1),
+			Self::C => ("c", 1),
+			Self::B => ("b", 1),
+			Self::A => ("a", 1),
+			Self::AA => ("a", 2),
+			Self::AAA => ("a", 3),
+			Self::S => ("aaa", 1),
+			Self::SS => ("aaa", 2),
+			Self::SSS => ("aaa", 3),
+			Self::Ex => ("sss", 1),
+		};
+		(format!("{REPO_ROOT}/ui/common/hero/quality_{id}.png"), rep)
+	}
+}
+
+#[bitflags]
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	Display_with_Serialize,
+	EnumAlias,
+	EnumVariantIter,
+)]
+#[repr(u16)]
+pub enum Element {
+	#[serde(alias = "p")]
+	Physical,
+	#[serde(alias = "m")]
+	Magic,
+	#[serde(alias = "f")]
+	Fire,
+	#[serde(alias = "l")]
+	Lightning,
+	#[serde(alias = "t")]
+	Tempest,
+	#[serde(alias = "i")]
+	Frost,
+	#[serde(alias = "h")]
+	Holy,
+	#[serde(alias = "d")]
+	Dark,
+	#[serde(alias = "s")]
+	Spiritual,
+	#[serde(alias = "v")]
+	Space,
+}
+impl Element {
+	#[inline]
+	pub fn icon(&self) -> Option<String> {
+		match self {
+			Self::Physical => Some(5),
+			Self::Magic => Some(1),
+			Self::Fire => Some(2),
+			Self::Lightning => Some(3),
+			Self::Tempest => Some(4),
+			Self::Frost => Some(6),
+			Self::Holy => Some(7),
+			Self::Dark => Some(8),
+			_ => None,
+		}
+		.map(|id: u8| format!("{REPO_ROOT}/icon/element/{id}.png"))
+	}
+}
+
+/// Broad category of a form, derived from its name
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+pub enum FormCategory {
+	Spirit,
+	#[serde(rename = "Inverse Spirit")]
+	InverseSpirit,
+	Wizard,
+	/// Any other form, such as an awakening or a collaboration character
+	#[serde(rename = "Alternate form")]
+	Alternate,
+}
+
+/// Stat of a spirit in the 'Date A Live' lore
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	FromStr_with_Deserialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum LoreStat {
+	Strength,
+	Consistency,
+	SpiritPower,
+	Agility,
+	Intelligence,
+}
+impl LoreStat {
+	/// Highest value of the scale
+	pub const MAX: u16 = 300;
+
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::Strength => "Strength",
+			Self::Consistency => "Consistency",
+			Self::SpiritPower => "Spiritual Power",
+			Self::Agility => "Agility",
+			Self::Intelligence => "Intelligence",
+		}
+	}
+
+	pub const fn abbrev(&self) -> &'static str {
+		match self {
+			Self::Strength => "STR",
+			Self::Consistency => "CST",
+			Self::SpiritPower => "SPI",
+			Self::Agility => "AGI",
+			Self::Intelligence => "INT",
+		}
+	}
+
+	#[inline]
+	pub fn of(&self, spirit: &Spirit) -> Option<u16> {
+		match self {
+			Self::Strength => spirit.strength,
+			Self::Consistency => spirit.consistency,
+			Self::SpiritPower => spirit.spirit_power,
+			Self::Agility => spirit.agility,
+			Self::Intelligence => spirit.intelligence,
+		}
+	}
+}
+
+/// Stat of a spirit in the 'Spirit Pledge' game
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Hash,
+	Deserialize,
+	Serialize,
+	FromStr_with_Deserialize,
+	Display_with_Serialize,
+	EnumVariantIter,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GachaStat {
+	Attack,
+	Combo,
+	Support,
+	Defense,
+	Control,
+	Damage,
+}
+impl GachaStat {
+	/// Highest value of the scale
+	pub const MAX: u8 = 100;
+
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::Attack => "Attack",
+			Self::Combo => "Combination",
+			Self::Support => "Support",
+			Self::Defense => "Defense",
+			Self::Control => "Control",
+			Self::Damage => "Damage",
+		}
+	}
+
+	pub const fn abbrev(&self) -> &'static str {
+		match self {
+			Self::Attack => "ATK",
+			Self::Combo => "CMB",
+			Self::Support => "SUP",
+			Self::Defense => "DEF",
+			Self::Control => "CTL",
+			Self::Damage => "DMG",
+		}
+	}
+
+	#[inline]
+	pub fn of(&self, spirit: &Spirit) -> Option<u8> {
+		match self {
+			Self::Attack => spirit.attack,
+			Self::Combo => spirit.combo,
+			Self::Support => spirit.support,
+			Self::Defense => spirit.defense,
+			Self::Control => spirit.control,
+			Self::Damage => spirit.damage,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Spirit {
+	series: Option<Series>,
+	pub firstname: String,
+	pub lastname: Option<String>,
+	gender: Option<Gender>,
+	codename: Option<String>,
+	pub form: String,
+	#[serde(default)]
+	pub media: CharFlags<Medium>,
+	icon_id: Option<String>,
+	image_id: Option<String>,
+	#[serde(default)]
+	pub sephiras: Vec<Sephira>,
+	#[serde(default)]
+	pub angels: Vec<Angel>,
+	#[serde(default)]
+	pub astraldresses: Vec<AstralDress>,
+	pub birthdate: Option<Birthdate>,
+	pub height: Option<u8>,
+	pub weight: Option<u8>,
+	pub bust: Option<u8>,
+	pub waist: Option<u8>,
+	pub hips: Option<u8>,
+	pub class: Option<Class>,
+	pub spacequake_class: Option<Class>,
+	pub angel_class: Option<Class>,
+	pub astraldress_class: Option<Class>,
+	pub strength: Option<u16>,
+	pub consistency: Option<u16>,
+	pub spirit_power: Option<u16>,
+	pub agility: Option<u16>,
+	pub intelligence: Option<u16>,
+	pub rank: Option<Class>,
+	pub attack: Option<u8>,
+	pub combo: Option<u8>,
+	pub support: Option<u8>,
+	pub defense: Option<u8>,
+	pub control: Option<u8>,
+	pub damage: Option<u8>,
+	#[serde(default)]
+	pub elements: CharFlags<Element>,
+	wiki_link: Option<String>,
+	spoiler: Option<bool>,
+	chara_slug: String,
+	form_slug: String,
+	#[serde(default)]
+	aliases: Vec<String>,
+}
+impl Spirit {
+	#[inline]
+	pub fn series(&self) -> Series {
+		self.series.unwrap_or_default()
+	}
+
+	/// Get the identifier of the character, shared by all its forms
+	#[inline(always)]
+	pub fn chara_slug(&self) -> &str {
+		&self.chara_slug
+	}
+
+	/// Get the identifier of the form, unique among the forms of the character
+	#[inline(always)]
+	pub fn form_slug(&self) -> &str {
+		&self.form_slug
+	}
+
+	/// Get the former slugs and other aliases of the spirit, see [`slug::parse_alias`](crate::slug::parse_alias)
+	#[inline(always)]
+	pub fn aliases(&self) -> &[String] {
+		&self.aliases
+	}
+
+	/// Derive a slug from the name of the form
+	pub fn form_url(&self) -> String {
+		use once_cell::sync::Lazy;
+		use regex::Regex;
+
+		static RE_NOT_WORD: Lazy<Regex> = Lazy::new(|| Regex::new(r"\W").unwrap());
+		static RE_PARENTHESIS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\((.+)\)").unwrap());
+
+		RE_NOT_WORD
+			.replace_all(&RE_PARENTHESIS.replace_all(&self.form, "_$1"), "")
+			.to_string()
+	}
+
+	#[inline]
+	pub fn gender(&self) -> Gender {
+		self.gender.unwrap_or_default()
+	}
+
+	#[inline]
+	pub fn a11y(&self) -> String {
+		format!("{form} {name}", form = self.form, name = self.firstname)
+	}
+
+	#[inline]
+	pub fn codename(&self) -> Option<(Codename, &str)> {
+		self.codename
+			.as_ref()
+			.map(|term| (self.series().into(), term.as_str()))
+	}
+
+	#[inline]
+	pub fn icon(&self) -> Option<String> {
+		self.icon_id
+			.as_deref()
+			.map(|id| format!("{REPO_ROOT}/icon/hero/face/{id}.png"))
+	}
+
+	#[inline]
+	pub fn image(&self) -> Option<String> {
+		use once_cell::sync::Lazy;
+		use regex::Regex;
+
+		self.image_id
+			.as_deref()
+			.map(|id| {
+				static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+$").unwrap());
+
+				if RE.is_match(id) {
+					format!("{REPO_ROOT}/icon/teampic/{id}.png")
+				} else {
+					format!("https://static.wikia.nocookie.net/date-a-live/images/{id}.png")
+				}
+			})
+			.or_else(|| {
+				self.icon_id
+					.as_ref()
+					.map(|id| format!("{REPO_ROOT}/icon/teampic/{id}.png"))
+			})
+	}
+
+	#[inline]
+	pub fn wiki_link(&self) -> Option<String> {
+		self.wiki_link
+			.as_ref()
+			.map(|wiki_link| format!("{FANDOM_ROOT}/{wiki_link}"))
+	}
+
+	#[inline]
+	pub fn spoiler(&self) -> bool {
+		self.spoiler.unwrap_or_default()
+	}
+
+	/// Get the categories of the form, as a form such as "Wizard Spirit" belongs to several
+	pub fn form_categories(&self) -> Vec<FormCategory> {
+		let mut categories = Vec::new();
+		if self.form.contains("Inverse") {
+			categories.push(FormCategory::InverseSpirit);
+		} else if self.form.contains("Spirit") {
+			categories.push(FormCategory::Spirit);
+		}
+		if self.form.contains("Wizard") {
+			categories.push(FormCategory::Wizard);
+		}
+		if categories.is_empty() {
+			categories.push(FormCategory::Alternate);
+		}
+		categories
+	}
+
+	/// Sum the lore stats, if they are all known
+	pub fn lore_total(&self) -> Option<u16> {
+		LoreStat::variants()
+			.into_iter()
+			.try_fold(0, |total, stat| Some(total + stat.of(self)?))
+	}
+
+	/// Sum the gacha stats, if they are all known
+	pub fn gacha_total(&self) -> Option<u16> {
+		GachaStat::variants()
+			.into_iter()
+			.try_fold(0, |total, stat| Some(total + u16::from(stat.of(self)?)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Birthdate, Date, Month};
+	use crate::BirthdateError;
+
+	fn date(year: i32, month: Month, day: u8) -> Date {
+		Date::new(year, month, day).unwrap()
+	}
+
+	#[test]
+	fn birthdate_days() {
+		assert!(Birthdate::new(Month::February, 29).is_ok());
+		assert!(Birthdate::new(Month::December, 31).is_ok());
+		assert_eq!(
+			Birthdate::new(Month::April, 31),
+			Err(BirthdateError::NoSuchDay(Month::April, 31))
+		);
+		assert!(Birthdate::new(Month::February, 30).is_err());
+		assert!(Birthdate::new(Month::January, 0).is_err());
+		assert!("April 31".parse::<Birthdate>().is_err());
+		assert_eq!(
+			"February 29".parse::<Birthdate>(),
+			Birthdate::new(Month::February, 29)
+		);
+	}
+
+	#[test]
+	fn birthdate_ordinal() {
+		let ordinal = |month, day| Birthdate::new(month, day).unwrap().ordinal();
+		assert_eq!(ordinal(Month::January, 1), 1);
+		assert_eq!(ordinal(Month::February, 28), 59);
+		assert_eq!(ordinal(Month::February, 29), 60);
+		assert_eq!(ordinal(Month::March, 1), 61);
+		assert_eq!(ordinal(Month::December, 31), 366);
+	}
+
+	#[test]
+	fn birthdate_days_until() {
+		let new_year = Birthdate::new(Month::January, 1).unwrap();
+		let new_year_eve = Birthdate::new(Month::December, 31).unwrap();
+		let today = date(2023, Month::December, 31);
+		assert_eq!(new_year_eve.days_until(today), 0);
+		assert_eq!(new_year.days_until(today), 1);
+		assert_eq!(
+			Birthdate::new(Month::December, 30)
+				.unwrap()
+				.days_until(today),
+			365
+		);
+		assert_eq!(new_year_eve.days_until(date(2024, Month::January, 1)), 365);
+	}
+
+	#[test]
+	fn leap_day_days_until() {
+		let leap_day = Birthdate::new(Month::February, 29).unwrap();
+		assert_eq!(leap_day.days_until(date(2024, Month::February, 28)), 1);
+		assert_eq!(leap_day.days_until(date(2024, Month::February, 29)), 0);
+		// Celebrated on February 28th in common years
+		assert_eq!(leap_day.days_until(date(2023, Month::February, 28)), 0);
+		assert!(leap_day.is_today(date(2023, Month::February, 28)));
+		assert_eq!(leap_day.days_until(date(2023, Month::March, 1)), 365);
+		assert_eq!(leap_day.days_until(date(2024, Month::March, 1)), 364);
+	}
+}
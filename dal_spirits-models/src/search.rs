@@ -0,0 +1,259 @@
+//! Fuzzy full-text search of spirits
+use crate::{Attribute, Spirit};
+
+/// Lowercase a text and strip its diacritics
+pub fn normalize(text: &str) -> String {
+	use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+	text.nfd()
+		.filter(|c| !is_combining_mark(*c))
+		.flat_map(char::to_lowercase)
+		.collect()
+}
+
+/// Compute the Levenshtein distance between two strings, counted in characters
+pub fn levenshtein(a: &str, b: &str) -> usize {
+	let b = b.chars().collect::<Vec<_>>();
+	let mut row = (0..=b.len()).collect::<Vec<_>>();
+	for (i, ca) in a.chars().enumerate() {
+		let mut diag = row[0];
+		row[0] = i + 1;
+		for (j, cb) in b.iter().enumerate() {
+			let above = row[j + 1];
+			row[j + 1] = if ca == *cb {
+				diag
+			} else {
+				1 + diag.min(above).min(row[j])
+			};
+			diag = above;
+		}
+	}
+	row[b.len()]
+}
+
+/// Get the number of typos tolerated in a search term
+#[inline]
+fn tolerance(term: &str) -> usize {
+	match term.chars().count() {
+		0..=3 => 0,
+		4..=7 => 1,
+		_ => 2,
+	}
+}
+
+/// Rank how well a term matches a word, lower being better
+fn word_score(term: &str, word: &str) -> Option<u32> {
+	if word == term {
+		Some(0)
+	} else if word.starts_with(term) {
+		Some(1)
+	} else if word.contains(term) {
+		Some(2)
+	} else {
+		// Compare with the beginning of the word too, to forgive typos while typing
+		let prefix = word
+			.char_indices()
+			.nth(term.chars().count())
+			.map_or(word, |(idx, _)| &word[..idx]);
+		let dist = levenshtein(term, word).min(levenshtein(term, prefix) + 1);
+		(dist <= tolerance(term)).then_some(2 + dist as u32)
+	}
+}
+
+/// Normalized search query
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+	terms: Vec<String>,
+}
+impl Query {
+	pub fn new(text: &str) -> Self {
+		Self {
+			terms: normalize(text)
+				.split(|c: char| !c.is_alphanumeric())
+				.filter(|term| !term.is_empty())
+				.map(str::to_string)
+				.collect(),
+		}
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.terms.is_empty()
+	}
+
+	/// Rank how well a spirit matches the query, lower being better
+	///
+	/// Every term must match one of the names of the spirit, with some typos allowed.
+	/// Names of the character weigh more than the names of their attributes.
+	/// Returns `None` if the spirit does not match.
+	pub fn score(&self, spirit: &Spirit) -> Option<u32> {
+		// Penalty of each kind of field
+		let mut fields = vec![(0, spirit.firstname.as_str())];
+		if let Some(ref lastname) = spirit.lastname {
+			fields.push((0, lastname.as_str()));
+		}
+		if let Some((_, codename)) = spirit.codename() {
+			fields.push((1, codename));
+		}
+		fields.push((1, spirit.form.as_str()));
+		fields.extend(spirit.sephiras.iter().map(|sephira| (2, sephira.name())));
+		fields.extend(spirit.angels.iter().map(|angel| (2, angel.name())));
+		fields.extend(
+			spirit
+				.astraldresses
+				.iter()
+				.map(|astraldress| (2, astraldress.name())),
+		);
+		let words = fields
+			.into_iter()
+			.flat_map(|(penalty, field)| {
+				normalize(field)
+					.split(|c: char| !c.is_alphanumeric())
+					.filter(|word| !word.is_empty())
+					.map(|word| (penalty, word.to_string()))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		self.terms.iter().try_fold(0, |total, term| {
+			words
+				.iter()
+				.filter_map(|(penalty, word)| word_score(term, word).map(|score| score + penalty))
+				.min()
+				.map(|score| total + score)
+		})
+	}
+}
+
+/// Find the spirits closest to a mistyped character slug, and optionally form slug
+///
+/// The character is compared both to [`Spirit::chara_slug`] and to the first name, and the form to [`Spirit::form_slug`].
+/// At most `limit` spirits are returned, the closest first.
+pub fn suggest<I, S>(spirits: I, chara: &str, form: Option<&str>, limit: usize) -> Vec<S>
+where
+	I: IntoIterator<Item = S>,
+	S: AsRef<Spirit>,
+{
+	let chara = normalize(chara);
+	let form = form.map(normalize);
+	let max_dist = (chara.chars().count() / 3).max(2);
+
+	let mut suggestions = spirits
+		.into_iter()
+		.filter_map(|spirit| {
+			let dist = {
+				let spirit = spirit.as_ref();
+				let chara_dist = levenshtein(&chara, &normalize(spirit.chara_slug()))
+					.min(levenshtein(&chara, &normalize(&spirit.firstname)));
+				if chara_dist > max_dist {
+					return None;
+				}
+				let form_dist = form
+					.as_deref()
+					.map_or(0, |form| levenshtein(form, &normalize(spirit.form_slug())));
+				chara_dist + form_dist
+			};
+			Some((dist, spirit))
+		})
+		.collect::<Vec<_>>();
+	suggestions.sort_by_key(|(dist, _)| *dist);
+	suggestions
+		.into_iter()
+		.take(limit)
+		.map(|(_, spirit)| spirit)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{levenshtein, normalize, suggest, word_score, Query};
+	use crate::Spirit;
+	use serde_json::json;
+
+	fn spirit(firstname: &str, lastname: &str, form: &str) -> Spirit {
+		crate::test_utils::spirit(json!({
+			"firstname": firstname,
+			"lastname": lastname,
+			"form": form,
+			"chara_slug": firstname,
+			"form_slug": form.replace(' ', ""),
+		}))
+	}
+
+	#[test]
+	fn levenshtein_distance() {
+		assert_eq!(levenshtein("", ""), 0);
+		assert_eq!(levenshtein("tohka", ""), 5);
+		assert_eq!(levenshtein("", "tohka"), 5);
+		assert_eq!(levenshtein("tohka", "tohka"), 0);
+		assert_eq!(levenshtein("tohka", "toka"), 1);
+		assert_eq!(levenshtein("tohka", "tohak"), 2);
+		assert_eq!(levenshtein("kitten", "sitting"), 3);
+		assert_eq!(
+			levenshtein("kotori", "kurumi"),
+			levenshtein("kurumi", "kotori")
+		);
+		// Counted in characters, not in bytes
+		assert_eq!(levenshtein("é", "e"), 1);
+	}
+
+	#[test]
+	fn normalization() {
+		assert_eq!(normalize("Mukuro Hoshimiya"), "mukuro hoshimiya");
+		assert_eq!(normalize("Élisabeth"), "elisabeth");
+	}
+
+	#[test]
+	fn word_scores() {
+		assert_eq!(word_score("tohka", "tohka"), Some(0));
+		assert_eq!(word_score("toh", "tohka"), Some(1));
+		assert_eq!(word_score("ohk", "tohka"), Some(2));
+		assert_eq!(word_score("yatogamu", "yatogami"), Some(3));
+		// Typos in a prefix being typed
+		assert_eq!(word_score("tobiichx", "tobiichiorigami"), Some(4));
+		assert_eq!(word_score("tohak", "tohka"), None);
+		assert_eq!(word_score("xyz", "tohka"), None);
+	}
+
+	#[test]
+	fn rank_order() {
+		let spirits = [
+			spirit("Tohka", "Yatogami", "Spirit"),
+			spirit("Tohkan", "Yatogami", "Spirit"),
+			spirit("Kurumi", "Tokisaki", "Tohka Cosplay"),
+			spirit("Origami", "Tobiichi", "Spirit"),
+		];
+		let scores = |text: &str| {
+			let query = Query::new(text);
+			spirits
+				.iter()
+				.map(|spirit| query.score(spirit))
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(scores("tohka"), [Some(0), Some(1), Some(1), None]);
+		assert_eq!(scores("Tohka Yatogami"), [Some(0), Some(1), None, None]);
+		assert_eq!(scores("spirit"), [Some(1), Some(1), None, Some(1)]);
+		assert!(Query::new(" - ").is_empty());
+	}
+
+	#[test]
+	fn suggestions() {
+		let spirits = [
+			spirit("Tohka", "Yatogami", "Spirit"),
+			spirit("Tohka", "Yatogami", "Inverse Spirit"),
+			spirit("Kotori", "Itsuka", "Spirit"),
+		];
+		let suggested = |chara: &str, form: Option<&str>| {
+			suggest(spirits.iter().cloned().map(Box::new), chara, form, 5)
+				.into_iter()
+				.map(|spirit| spirit.form_slug().to_string())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			suggested("tohak", Some("InversSpirit")),
+			["InverseSpirit", "Spirit"]
+		);
+		assert_eq!(suggested("kotroi", None), ["Spirit"]);
+		assert!(suggested("origami", None).is_empty());
+	}
+}
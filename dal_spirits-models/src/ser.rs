@@ -0,0 +1,460 @@
+//! Serialization to the CSV dialect of `spirits.csv`
+//!
+//! This is the counterpart of [`csv_deserializer`](crate::csv_deserializer):
+//! lists are joined with `;` and the fields of their items with `:`.
+use serde::ser::{self, Impossible, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// Record and field separators of each nesting level
+pub(crate) const SEPARATORS: [(char, char); 2] = [('\n', ','), (';', ':')];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+impl Display for Error {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+impl std::error::Error for Error {}
+impl ser::Error for Error {
+	#[inline]
+	fn custom<T: Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+/// Serialize records to CSV, header included
+pub fn to_csv<T: Serialize>(records: &[T]) -> Result<String, Error> {
+	let (record_sep, field_sep) = SEPARATORS[0];
+	let mut csv = String::new();
+	for (idx, record) in records.iter().enumerate() {
+		let Output { value, keys } = record.serialize(ValueSerializer::default())?;
+		if keys.is_empty() {
+			return Err(Error("records must be structs".to_string()));
+		}
+		if idx == 0 {
+			csv.push_str(&keys.join(&field_sep.to_string()));
+			csv.push(record_sep);
+		}
+		csv.push_str(&value);
+		csv.push(record_sep);
+	}
+	Ok(csv)
+}
+
+struct Output {
+	value: String,
+	/// Field names, if the value is a struct
+	keys: Vec<&'static str>,
+}
+impl From<String> for Output {
+	#[inline(always)]
+	fn from(value: String) -> Self {
+		Self {
+			value,
+			keys: Vec::with_capacity(0),
+		}
+	}
+}
+
+#[derive(Default, Clone, Copy)]
+struct ValueSerializer {
+	level: usize,
+	in_seq: bool,
+}
+impl ValueSerializer {
+	fn scalar<V: Display>(self, value: V) -> Result<Output, Error> {
+		let value = value.to_string();
+		// The dialect has no escaping, so separators of enclosing levels are forbidden,
+		// as well as the one between the items of the enclosing list
+		let item_sep = self.in_seq.then(|| SEPARATORS[self.level].0);
+		match SEPARATORS[..self.level]
+			.iter()
+			.flat_map(|(record_sep, field_sep)| [*record_sep, *field_sep])
+			.chain(item_sep)
+			.find(|sep| value.contains(*sep))
+		{
+			Some(sep) => Err(Error(format!("{value:?} contains the separator {sep:?}"))),
+			None => Ok(value.into()),
+		}
+	}
+
+	fn compound(self, len: Option<usize>) -> Result<CompoundSerializer, Error> {
+		if self.level >= SEPARATORS.len() {
+			return Err(Error(format!(
+				"values cannot be nested more than {} times",
+				SEPARATORS.len()
+			)));
+		}
+		Ok(CompoundSerializer {
+			ser: self,
+			values: Vec::with_capacity(len.unwrap_or_default()),
+			keys: Vec::with_capacity(len.unwrap_or_default()),
+		})
+	}
+
+	#[inline]
+	fn unsupported(what: &str) -> Error {
+		Error(format!("{what} cannot be serialized to CSV"))
+	}
+}
+
+macro_rules! serialize_scalars {
+	($($method:ident: $ty:ty),* $(,)?) => {
+		$(
+			#[inline]
+			fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+				self.scalar(v)
+			}
+		)*
+	};
+}
+
+impl ser::Serializer for ValueSerializer {
+	type Ok = Output;
+	type Error = Error;
+	type SerializeSeq = CompoundSerializer;
+	type SerializeTuple = CompoundSerializer;
+	type SerializeTupleStruct = CompoundSerializer;
+	type SerializeTupleVariant = Impossible<Output, Error>;
+	type SerializeMap = Impossible<Output, Error>;
+	type SerializeStruct = CompoundSerializer;
+	type SerializeStructVariant = Impossible<Output, Error>;
+
+	serialize_scalars! {
+		serialize_bool: bool,
+		serialize_i8: i8,
+		serialize_i16: i16,
+		serialize_i32: i32,
+		serialize_i64: i64,
+		serialize_u8: u8,
+		serialize_u16: u16,
+		serialize_u32: u32,
+		serialize_u64: u64,
+		serialize_f32: f32,
+		serialize_f64: f64,
+		serialize_char: char,
+		serialize_str: &str,
+	}
+
+	fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+		Err(Self::unsupported("bytes"))
+	}
+
+	#[inline]
+	fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+		self.scalar("")
+	}
+
+	#[inline]
+	fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+		value.serialize(self)
+	}
+
+	#[inline]
+	fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+		self.scalar("")
+	}
+
+	#[inline]
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+		self.scalar("")
+	}
+
+	#[inline]
+	fn serialize_unit_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+	) -> Result<Self::Ok, Self::Error> {
+		self.scalar(variant)
+	}
+
+	#[inline]
+	fn serialize_newtype_struct<T: ?Sized + Serialize>(
+		self,
+		_name: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: ?Sized + Serialize>(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_value: &T,
+	) -> Result<Self::Ok, Self::Error> {
+		Err(Self::unsupported("enum variants with data"))
+	}
+
+	fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+		if self.in_seq || self.level == 0 {
+			return Err(Self::unsupported("nested lists"));
+		}
+		self.compound(len)
+	}
+
+	#[inline]
+	fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+		self.compound(Some(len))
+	}
+
+	#[inline]
+	fn serialize_tuple_struct(
+		self,
+		_name: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeTupleStruct, Self::Error> {
+		self.compound(Some(len))
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeTupleVariant, Self::Error> {
+		Err(Self::unsupported("enum variants with data"))
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+		Err(Self::unsupported("maps"))
+	}
+
+	#[inline]
+	fn serialize_struct(
+		self,
+		_name: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeStruct, Self::Error> {
+		self.compound(Some(len))
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		_variant_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStructVariant, Self::Error> {
+		Err(Self::unsupported("enum variants with data"))
+	}
+}
+
+struct CompoundSerializer {
+	ser: ValueSerializer,
+	values: Vec<String>,
+	keys: Vec<&'static str>,
+}
+impl CompoundSerializer {
+	/// Serialize an item of a list, which lives on the same level as the list
+	fn push_item<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		let ser = ValueSerializer {
+			in_seq: true,
+			..self.ser
+		};
+		self.values.push(value.serialize(ser)?.value);
+		Ok(())
+	}
+
+	/// Serialize a field of a record, which lives on the next level
+	fn push_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+		let ser = ValueSerializer {
+			level: self.ser.level + 1,
+			in_seq: false,
+		};
+		self.values.push(value.serialize(ser)?.value);
+		Ok(())
+	}
+
+	fn end_with(self, sep: char) -> Result<Output, Error> {
+		Ok(Output {
+			value: self.values.join(&sep.to_string()),
+			keys: self.keys,
+		})
+	}
+
+	#[inline]
+	fn end_record(self) -> Result<Output, Error> {
+		let (_, field_sep) = SEPARATORS[self.ser.level];
+		self.end_with(field_sep)
+	}
+}
+impl ser::SerializeSeq for CompoundSerializer {
+	type Ok = Output;
+	type Error = Error;
+
+	#[inline]
+	fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+		self.push_item(value)
+	}
+
+	#[inline]
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		let (record_sep, _) = SEPARATORS[self.ser.level];
+		self.end_with(record_sep)
+	}
+}
+impl ser::SerializeTuple for CompoundSerializer {
+	type Ok = Output;
+	type Error = Error;
+
+	#[inline]
+	fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+		self.push_field(value)
+	}
+
+	#[inline]
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		self.end_record()
+	}
+}
+impl ser::SerializeTupleStruct for CompoundSerializer {
+	type Ok = Output;
+	type Error = Error;
+
+	#[inline]
+	fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+		self.push_field(value)
+	}
+
+	#[inline]
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		self.end_record()
+	}
+}
+impl ser::SerializeStruct for CompoundSerializer {
+	type Ok = Output;
+	type Error = Error;
+
+	#[inline]
+	fn serialize_field<T: ?Sized + Serialize>(
+		&mut self,
+		key: &'static str,
+		value: &T,
+	) -> Result<(), Self::Error> {
+		self.keys.push(key);
+		self.push_field(value)
+	}
+
+	#[inline]
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		self.end_record()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::to_csv;
+	use serde::Serialize;
+
+	#[derive(Serialize)]
+	struct Item {
+		name: &'static str,
+		rank: Option<u8>,
+	}
+
+	#[derive(Serialize)]
+	struct Record {
+		name: &'static str,
+		items: Vec<Item>,
+		tags: Vec<&'static str>,
+	}
+
+	#[test]
+	fn spirits_csv_round_trip() {
+		const CSV: &str = include_str!("../../assets/spirits.csv");
+		let spirits = crate::from_csv(CSV).unwrap();
+		assert_eq!(to_csv(&spirits).unwrap(), CSV);
+	}
+
+	#[test]
+	fn fields_and_records() {
+		let records = [
+			Record {
+				name: "Tohka",
+				items: Vec::new(),
+				tags: vec!["a"],
+			},
+			Record {
+				name: "Origami",
+				items: Vec::new(),
+				tags: Vec::new(),
+			},
+		];
+		assert_eq!(
+			to_csv(&records).unwrap(),
+			"name,items,tags\nTohka,,a\nOrigami,,\n"
+		);
+	}
+
+	#[test]
+	fn list_separators() {
+		let record = Record {
+			name: "Tohka",
+			items: vec![
+				Item {
+					name: "Sandalphon",
+					rank: Some(1),
+				},
+				Item {
+					name: "Shekinah",
+					rank: None,
+				},
+			],
+			tags: vec!["a", "b"],
+		};
+		assert_eq!(
+			to_csv(&[record]).unwrap(),
+			"name,items,tags\nTohka,Sandalphon:1;Shekinah:,a;b\n"
+		);
+	}
+
+	#[test]
+	fn scalar_separators() {
+		for name in ["To,hka", "To\nhka"] {
+			let record = Record {
+				name,
+				items: Vec::new(),
+				tags: Vec::new(),
+			};
+			assert!(to_csv(&[record]).is_err(), "{name:?}");
+		}
+		for tag in ["a;b", "a,b"] {
+			let record = Record {
+				name: "Tohka",
+				items: Vec::new(),
+				tags: vec![tag],
+			};
+			assert!(to_csv(&[record]).is_err(), "{tag:?}");
+		}
+		let record = Record {
+			name: "Tohka",
+			items: vec![Item {
+				name: "Sandal;phon",
+				rank: None,
+			}],
+			tags: Vec::new(),
+		};
+		assert!(to_csv(&[record]).is_err());
+	}
+
+	#[test]
+	fn unsupported_values() {
+		#[derive(Serialize)]
+		struct Nested {
+			lists: Vec<Vec<u8>>,
+		}
+
+		assert!(to_csv(&["Tohka"]).is_err());
+		assert!(to_csv(&[Nested {
+			lists: vec![vec![1]],
+		}])
+		.is_err());
+	}
+}
@@ -0,0 +1,218 @@
+//! Identifiers of characters and spirits in URLs and in the DOM
+use crate::Spirit;
+use std::{
+	collections::HashMap,
+	fmt::{self, Display, Formatter},
+};
+
+/// Check that a slug is non-empty and only made of ASCII alphanumerics, `_` and `-`
+#[inline]
+pub fn is_valid(slug: &str) -> bool {
+	!slug.is_empty()
+		&& slug
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Split an alias into its character slug and its optional form slug
+///
+/// An alias is either a single slug, such as a codename, standing for the character of the spirit,
+/// or a `chara/form` pair of slugs standing for the spirit itself.
+#[inline]
+pub fn parse_alias(alias: &str) -> (&str, Option<&str>) {
+	match alias.split_once('/') {
+		Some((chara, form)) => (chara, Some(form)),
+		None => (alias, None),
+	}
+}
+
+/// Slug issue found by [`check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugError {
+	/// Index of the faulty spirit
+	pub index: usize,
+	/// Name of the faulty field
+	pub field: &'static str,
+	pub message: String,
+}
+impl Display for SlugError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{field}: {msg}", field = self.field, msg = self.message)
+	}
+}
+impl std::error::Error for SlugError {}
+
+/// Check that slugs are well-formed and that they identify a single character or spirit
+///
+/// Aliases must not shadow the slugs of another character or spirit either.
+pub fn check<'s, I>(spirits: I) -> Vec<SlugError>
+where
+	I: IntoIterator<Item = &'s Spirit>,
+{
+	let mut errors = Vec::new();
+	let mut charas: HashMap<&str, (usize, &Spirit)> = HashMap::new();
+	let mut ids: HashMap<(&str, &str), usize> = HashMap::new();
+	let mut aliases = Vec::new();
+	for (index, spirit) in spirits.into_iter().enumerate() {
+		aliases.extend(spirit.aliases().iter().map(|alias| (index, alias.as_str())));
+		let (chara_slug, form_slug) = (spirit.chara_slug(), spirit.form_slug());
+		for (field, slug) in [("chara_slug", chara_slug), ("form_slug", form_slug)] {
+			if !is_valid(slug) {
+				errors.push(SlugError {
+					index,
+					field,
+					message: format!("{slug:?} is not a valid slug"),
+				});
+			}
+		}
+
+		match charas.get(chara_slug) {
+			Some((first_index, first))
+				if first.firstname != spirit.firstname || first.lastname != spirit.lastname =>
+			{
+				errors.push(SlugError {
+					index,
+					field: "chara_slug",
+					message: format!(
+						"{chara_slug:?} is already used by another character at index {first_index}"
+					),
+				});
+			}
+			Some(_) => {}
+			None => {
+				charas.insert(chara_slug, (index, spirit));
+			}
+		}
+
+		if let Some(first_index) = ids.insert((chara_slug, form_slug), index) {
+			errors.push(SlugError {
+				index,
+				field: "form_slug",
+				message: format!(
+					"\"{chara_slug}/{form_slug}\" is already used at index {first_index}"
+				),
+			});
+		}
+	}
+
+	let mut chara_aliases: HashMap<&str, usize> = HashMap::new();
+	let mut form_aliases: HashMap<(&str, &str), usize> = HashMap::new();
+	for (index, alias) in aliases {
+		let error = |message| SlugError {
+			index,
+			field: "aliases",
+			message,
+		};
+		let (chara_slug, form_slug) = parse_alias(alias);
+		if !is_valid(chara_slug) || !form_slug.map_or(true, is_valid) {
+			errors.push(error(format!("{alias:?} is not a valid alias")));
+			continue;
+		}
+		let first_index = match form_slug {
+			None => {
+				if let Some((chara_index, _)) = charas.get(chara_slug) {
+					errors.push(error(format!(
+						"{alias:?} is already used by the character at index {chara_index}"
+					)));
+					continue;
+				}
+				chara_aliases.insert(chara_slug, index)
+			}
+			Some(form_slug) => {
+				if let Some(id_index) = ids.get(&(chara_slug, form_slug)) {
+					errors.push(error(format!(
+						"{alias:?} is already used by the spirit at index {id_index}"
+					)));
+					continue;
+				}
+				form_aliases.insert((chara_slug, form_slug), index)
+			}
+		};
+		if let Some(first_index) = first_index {
+			errors.push(error(format!(
+				"{alias:?} is already an alias at index {first_index}"
+			)));
+		}
+	}
+	errors
+}
+
+#[cfg(test)]
+mod tests {
+	use super::check;
+	use crate::Spirit;
+	use serde_json::json;
+
+	fn spirit(firstname: &str, chara_slug: &str, form_slug: &str, aliases: &[&str]) -> Spirit {
+		crate::test_utils::spirit(json!({
+			"firstname": firstname,
+			"form": form_slug,
+			"chara_slug": chara_slug,
+			"form_slug": form_slug,
+			"aliases": aliases,
+		}))
+	}
+
+	/// Get the index and field of each error
+	fn errors(spirits: &[Spirit]) -> Vec<(usize, &'static str)> {
+		check(spirits)
+			.into_iter()
+			.map(|err| (err.index, err.field))
+			.collect()
+	}
+
+	#[test]
+	fn valid_slugs() {
+		let spirits = [
+			spirit("Tohka", "Tohka", "Spirit", &["Princess", "Tohka/Astral"]),
+			spirit("Tohka", "Tohka", "Inverse", &[]),
+			spirit("Origami", "Origami", "Spirit", &["Angel"]),
+		];
+		assert_eq!(errors(&spirits), []);
+	}
+
+	#[test]
+	fn invalid_slugs() {
+		let spirits = [
+			spirit("Tohka", "Tohka Yatogami", "Spirit", &[]),
+			spirit("Origami", "Origami", "", &["Origami/"]),
+		];
+		assert_eq!(
+			errors(&spirits),
+			[(0, "chara_slug"), (1, "form_slug"), (1, "aliases")]
+		);
+	}
+
+	#[test]
+	fn duplicate_slugs() {
+		let spirits = [
+			spirit("Tohka", "Tohka", "Spirit", &[]),
+			spirit("Tohka", "Tohka", "Spirit", &[]),
+			spirit("Tenka", "Tohka", "Inverse", &[]),
+		];
+		assert_eq!(errors(&spirits), [(1, "form_slug"), (2, "chara_slug")]);
+	}
+
+	#[test]
+	fn colliding_aliases() {
+		let spirits = [
+			spirit("Tohka", "Tohka", "Spirit", &["Origami", "Tohka/Inverse"]),
+			spirit("Tohka", "Tohka", "Inverse", &["Princess"]),
+			spirit(
+				"Origami",
+				"Origami",
+				"Spirit",
+				&["Princess", "Origami/Spirit"],
+			),
+		];
+		assert_eq!(
+			errors(&spirits),
+			[
+				(0, "aliases"),
+				(0, "aliases"),
+				(2, "aliases"),
+				(2, "aliases")
+			]
+		);
+	}
+}
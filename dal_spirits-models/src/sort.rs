@@ -0,0 +1,241 @@
+//! Orderings of the list of spirits
+use crate::{GachaStat, LoreStat, Spirit};
+use std::{
+	cmp::Ordering,
+	fmt::{self, Display, Formatter},
+	str::FromStr,
+};
+
+const KEY_SORT: &str = "sort";
+const KEY_TOTAL_LORE: &str = "lore_total";
+const KEY_TOTAL_GACHA: &str = "gacha_total";
+
+/// Value by which spirits can be sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortKey {
+	Name,
+	Birthdate,
+	Height,
+	Class,
+	Rank,
+	Lore(LoreStat),
+	LoreTotal,
+	Gacha(GachaStat),
+	GachaTotal,
+}
+impl SortKey {
+	/// Get all the keys, in the order of a selector
+	pub fn variants() -> Vec<Self> {
+		[
+			Self::Name,
+			Self::Birthdate,
+			Self::Height,
+			Self::Class,
+			Self::Rank,
+		]
+		.into_iter()
+		.chain(LoreStat::variants().into_iter().map(Self::Lore))
+		.chain([Self::LoreTotal])
+		.chain(GachaStat::variants().into_iter().map(Self::Gacha))
+		.chain([Self::GachaTotal])
+		.collect()
+	}
+
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::Name => "Name",
+			Self::Birthdate => "Birthdate",
+			Self::Height => "Height",
+			Self::Class => "Class",
+			Self::Rank => "Rank",
+			Self::Lore(stat) => stat.name(),
+			Self::LoreTotal => "Lore stats total",
+			Self::Gacha(stat) => stat.name(),
+			Self::GachaTotal => "Gacha stats total",
+		}
+	}
+
+	/// Whether the key ranks the highest values first by default
+	#[inline]
+	pub fn is_ranking(&self) -> bool {
+		!matches!(self, Self::Name | Self::Birthdate)
+	}
+
+	/// Compare two spirits by this key alone, in ascending order
+	///
+	/// Spirits missing the value come last.
+	pub fn compare(&self, a: &Spirit, b: &Spirit) -> Ordering {
+		fn present_first<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+			match (a, b) {
+				(Some(a), Some(b)) => a.cmp(&b),
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(None, None) => Ordering::Equal,
+			}
+		}
+
+		match self {
+			Self::Name => crate::search::normalize(&a.firstname)
+				.cmp(&crate::search::normalize(&b.firstname))
+				.then_with(|| present_first(a.lastname.as_ref(), b.lastname.as_ref())),
+			Self::Birthdate => present_first(a.birthdate, b.birthdate),
+			Self::Height => present_first(a.height, b.height),
+			Self::Class => present_first(a.class, b.class),
+			Self::Rank => present_first(a.rank, b.rank),
+			Self::Lore(stat) => present_first(stat.of(a), stat.of(b)),
+			Self::LoreTotal => present_first(a.lore_total(), b.lore_total()),
+			Self::Gacha(stat) => present_first(stat.of(a), stat.of(b)),
+			Self::GachaTotal => present_first(a.gacha_total(), b.gacha_total()),
+		}
+	}
+
+	/// Whether a spirit has a value for this key, without formatting it
+	pub fn is_known(&self, spirit: &Spirit) -> bool {
+		match self {
+			Self::Name => true,
+			Self::Birthdate => spirit.birthdate.is_some(),
+			Self::Height => spirit.height.is_some(),
+			Self::Class => spirit.class.is_some(),
+			Self::Rank => spirit.rank.is_some(),
+			Self::Lore(stat) => stat.of(spirit).is_some(),
+			Self::LoreTotal => spirit.lore_total().is_some(),
+			Self::Gacha(stat) => stat.of(spirit).is_some(),
+			Self::GachaTotal => spirit.gacha_total().is_some(),
+		}
+	}
+
+	/// Format the value of a spirit for this key, if it has one
+	pub fn value(&self, spirit: &Spirit) -> Option<String> {
+		match self {
+			Self::Name => Some(match spirit.lastname {
+				Some(ref lastname) => format!("{} {lastname}", spirit.firstname),
+				None => spirit.firstname.clone(),
+			}),
+			Self::Birthdate => spirit.birthdate.map(|birthdate| birthdate.to_string()),
+			Self::Height => spirit.height.map(|height| format!("{height} cm")),
+			Self::Class => spirit.class.map(|class| class.to_string()),
+			Self::Rank => spirit.rank.map(|rank| rank.to_string()),
+			Self::Lore(stat) => stat.of(spirit).map(|value| value.to_string()),
+			Self::LoreTotal => spirit.lore_total().map(|total| total.to_string()),
+			Self::Gacha(stat) => stat.of(spirit).map(|value| value.to_string()),
+			Self::GachaTotal => spirit.gacha_total().map(|total| total.to_string()),
+		}
+	}
+}
+impl Display for SortKey {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Name => f.write_str("name"),
+			Self::Birthdate => f.write_str("birthdate"),
+			Self::Height => f.write_str("height"),
+			Self::Class => f.write_str("class"),
+			Self::Rank => f.write_str("rank"),
+			Self::Lore(stat) => Display::fmt(stat, f),
+			Self::LoreTotal => f.write_str(KEY_TOTAL_LORE),
+			Self::Gacha(stat) => Display::fmt(stat, f),
+			Self::GachaTotal => f.write_str(KEY_TOTAL_GACHA),
+		}
+	}
+}
+impl FromStr for SortKey {
+	type Err = ();
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::variants()
+			.into_iter()
+			.find(|key| key.to_string() == s)
+			.ok_or(())
+	}
+}
+
+/// Ordering of the spirits by a [`SortKey`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sort {
+	pub key: SortKey,
+	pub descending: bool,
+}
+impl Sort {
+	/// Sort by `key` in its default direction
+	#[inline]
+	pub fn new(key: SortKey) -> Self {
+		Self {
+			key,
+			descending: key.is_ranking(),
+		}
+	}
+
+	/// Compare two spirits, spirits missing the value coming last in both directions
+	pub fn compare(&self, a: &Spirit, b: &Spirit) -> Ordering {
+		match (self.key.is_known(a), self.key.is_known(b)) {
+			(true, true) if self.descending => self.key.compare(b, a),
+			(true, true) => self.key.compare(a, b),
+			(known_a, known_b) => known_b.cmp(&known_a),
+		}
+	}
+
+	/// Encode the ordering as key-value pairs, for the query string of a URL
+	///
+	/// Descending orders are prefixed with `-`.
+	pub fn to_query(sort: Option<Self>) -> Vec<(&'static str, String)> {
+		sort.map(|sort| {
+			let sign = if sort.descending { "-" } else { "" };
+			(KEY_SORT, format!("{sign}{key}", key = sort.key))
+		})
+		.into_iter()
+		.collect()
+	}
+
+	/// Decode the ordering from key-value pairs made by [`to_query`](Self::to_query)
+	///
+	/// Unknown keys and invalid values are ignored.
+	pub fn from_query<'q, I>(query: I) -> Option<Self>
+	where
+		I: IntoIterator<Item = (&'q str, &'q str)>,
+	{
+		let (_, value) = query
+			.into_iter()
+			.filter(|(key, _)| *key == KEY_SORT)
+			.last()?;
+		let (descending, key) = match value.strip_prefix('-') {
+			Some(key) => (true, key),
+			None => (false, value),
+		};
+		Some(Self {
+			key: key.parse().ok()?,
+			descending,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Sort, SortKey};
+	use crate::test_utils::spirit;
+	use serde_json::json;
+
+	#[test]
+	fn known_values() {
+		let spirits = [
+			spirit(json!({
+				"height": 155,
+				"class": "AAA",
+				"strength": 230,
+			})),
+			spirit(json!({
+				"firstname": "Origami",
+				"chara_slug": "Origami",
+			})),
+		];
+		for key in SortKey::variants() {
+			for spirit in &spirits {
+				assert_eq!(key.is_known(spirit), key.value(spirit).is_some(), "{key}");
+			}
+		}
+		for key in [SortKey::Height, SortKey::Class] {
+			for descending in [false, true] {
+				let sort = Sort { key, descending };
+				assert!(sort.compare(&spirits[0], &spirits[1]).is_lt(), "{key}");
+			}
+		}
+	}
+}
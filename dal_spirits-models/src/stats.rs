@@ -0,0 +1,177 @@
+//! Statistics of the values of the spirits over the dataset
+use crate::Spirit;
+
+/// Range of values counted by [`Distribution::histogram`], `end` excluded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bin {
+	pub start: f32,
+	pub end: f32,
+	pub count: usize,
+}
+
+/// Known values of a field over a set of spirits
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Distribution {
+	/// Sorted in ascending order
+	values: Vec<f32>,
+}
+impl Distribution {
+	/// Collect values, ignoring NaN
+	pub fn new<I: IntoIterator<Item = f32>>(values: I) -> Self {
+		let mut values = values
+			.into_iter()
+			.filter(|value| !value.is_nan())
+			.collect::<Vec<_>>();
+		values.sort_unstable_by(f32::total_cmp);
+		Self { values }
+	}
+
+	/// Collect the values of the spirits that have one
+	pub fn of<I, S, F>(spirits: I, mut get: F) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<Spirit>,
+		F: FnMut(&Spirit) -> Option<f32>,
+	{
+		Self::new(
+			spirits
+				.into_iter()
+				.filter_map(|spirit| get(spirit.as_ref())),
+		)
+	}
+
+	#[inline(always)]
+	pub fn len(&self) -> usize {
+		self.values.len()
+	}
+
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Get the values, in ascending order
+	#[inline(always)]
+	pub fn values(&self) -> &[f32] {
+		&self.values
+	}
+
+	#[inline]
+	pub fn min(&self) -> Option<f32> {
+		self.values.first().copied()
+	}
+
+	#[inline]
+	pub fn max(&self) -> Option<f32> {
+		self.values.last().copied()
+	}
+
+	/// Get the arithmetic mean of the values
+	pub fn mean(&self) -> Option<f32> {
+		(!self.is_empty()).then(|| self.values.iter().sum::<f32>() / self.len() as f32)
+	}
+
+	/// Get the percentile rank of a value, between 0 and 100
+	///
+	/// Values equal to `value` count for half, so that the median value ranks 50.
+	pub fn percentile_rank(&self, value: f32) -> Option<f32> {
+		if self.is_empty() {
+			return None;
+		}
+		let below = self.values.partition_point(|other| *other < value);
+		let not_above = self.values.partition_point(|other| *other <= value);
+		let rank = below as f32 + (not_above - below) as f32 / 2f32;
+		Some(100f32 * rank / self.len() as f32)
+	}
+
+	/// Count the values in consecutive bins of the same width
+	///
+	/// Bins start at a multiple of `bin_width`, and there is no bin after the largest value.
+	pub fn histogram(&self, bin_width: f32) -> Vec<Bin> {
+		let (min, max) = match (self.min(), self.max()) {
+			(Some(min), Some(max)) if bin_width > 0f32 => (min, max),
+			_ => return Vec::new(),
+		};
+		let first = (min / bin_width).floor() * bin_width;
+		let len = ((max - first) / bin_width).floor() as usize + 1;
+		let mut bins = (0..len)
+			.map(|idx| {
+				let start = first + idx as f32 * bin_width;
+				Bin {
+					start,
+					end: start + bin_width,
+					count: 0,
+				}
+			})
+			.collect::<Vec<_>>();
+		for value in &self.values {
+			let idx = ((value - first) / bin_width).floor() as usize;
+			bins[idx.min(len - 1)].count += 1;
+		}
+		bins
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Bin, Distribution};
+
+	fn counts(bins: &[Bin]) -> Vec<(f32, usize)> {
+		bins.iter().map(|bin| (bin.start, bin.count)).collect()
+	}
+
+	#[test]
+	fn empty() {
+		let distribution = Distribution::new([]);
+		assert!(distribution.is_empty());
+		assert_eq!(distribution.histogram(5f32), []);
+		assert_eq!(distribution.percentile_rank(1f32), None);
+		assert_eq!(distribution.mean(), None);
+		assert!(Distribution::new([f32::NAN]).is_empty());
+	}
+
+	#[test]
+	fn histogram_edges() {
+		let distribution = Distribution::new([171f32, 150f32, 154.9, 155f32, 160f32]);
+		let bins = distribution.histogram(5f32);
+		assert_eq!(
+			counts(&bins),
+			[
+				(150f32, 2),
+				(155f32, 1),
+				(160f32, 1),
+				(165f32, 0),
+				(170f32, 1)
+			]
+		);
+		assert!(bins.iter().all(|bin| bin.end == bin.start + 5f32));
+
+		// The largest value opens a bin of its own when it is on an edge
+		let bins = Distribution::new([152f32, 155f32]).histogram(5f32);
+		assert_eq!(counts(&bins), [(150f32, 1), (155f32, 1)]);
+
+		let bins = Distribution::new([-3f32, 2f32]).histogram(5f32);
+		assert_eq!(counts(&bins), [(-5f32, 1), (0f32, 1)]);
+
+		assert_eq!(
+			counts(&Distribution::new([7f32; 3]).histogram(5f32)),
+			[(5f32, 3)]
+		);
+		assert_eq!(Distribution::new([7f32]).histogram(0f32), []);
+	}
+
+	#[test]
+	fn percentile_ties() {
+		let distribution = Distribution::new([2f32, 1f32, 3f32, 2f32]);
+		assert_eq!(distribution.percentile_rank(0f32), Some(0f32));
+		assert_eq!(distribution.percentile_rank(1f32), Some(12.5));
+		assert_eq!(distribution.percentile_rank(2f32), Some(50f32));
+		assert_eq!(distribution.percentile_rank(2.5), Some(75f32));
+		assert_eq!(distribution.percentile_rank(3f32), Some(87.5));
+		assert_eq!(distribution.percentile_rank(4f32), Some(100f32));
+
+		let distribution = Distribution::new([5f32; 3]);
+		assert_eq!(distribution.percentile_rank(5f32), Some(50f32));
+		assert_eq!(distribution.mean(), Some(5f32));
+	}
+}
@@ -0,0 +1,18 @@
+//! Helpers shared by the tests of the crate
+use crate::Spirit;
+use serde_json::{json, Value};
+
+/// Deserialize a spirit from some fields,
+/// the names and slugs being Tohka's Spirit form unless given
+pub(crate) fn spirit(fields: Value) -> Spirit {
+	let mut value = json!({
+		"firstname": "Tohka",
+		"form": "Spirit",
+		"chara_slug": "Tohka",
+		"form_slug": "Spirit",
+	});
+	if let (Value::Object(value), Value::Object(fields)) = (&mut value, fields) {
+		value.extend(fields);
+	}
+	serde_json::from_value(value).unwrap()
+}
@@ -0,0 +1,234 @@
+use enumflags2::{BitFlag, BitFlags};
+use serde::{
+	de::{self, IntoDeserializer, SeqAccess, Visitor},
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+	fmt::{self, Debug, Display, Formatter},
+	marker::PhantomData,
+	ops::{Deref, DerefMut},
+	str::FromStr,
+};
+
+/// Format a number to its [ordinal form](https://en.wikipedia.org/wiki/Ordinal_numeral)
+pub fn ordinal_number<N: Into<usize>>(n: N) -> String {
+	let n = n.into();
+	macro_rules! format_ordinal {
+		($card:expr) => {
+			format!("{n}{card}", card = $card)
+		};
+	}
+	if n > 10 && n < 20 {
+		format_ordinal!("th")
+	} else {
+		match n % 10 {
+			1 => format_ordinal!("st"),
+			2 => format_ordinal!("nd"),
+			3 => format_ordinal!("rd"),
+			_ => format_ordinal!("th"),
+		}
+	}
+}
+
+/// Enum variants with a short name in the CSV dialect
+pub trait Alias {
+	fn alias(&self) -> &'static str;
+}
+
+/// Serialize with [`Alias::alias`] in human-readable formats
+pub(crate) fn serialize_alias<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	T: Alias + Serialize,
+	S: Serializer,
+{
+	if serializer.is_human_readable() {
+		serializer.serialize_str(value.alias())
+	} else {
+		value.serialize(serializer)
+	}
+}
+
+/// [`BitFlags`] wrapper to (de)serialize using the [aliases](Alias) of the flags
+///
+/// A sequence of flags is also accepted when deserializing, for formats such as JSON.
+/// Non human-readable formats use the raw bits instead.
+pub struct CharFlags<T: BitFlag>(BitFlags<T>);
+impl<T: BitFlag> CharFlags<T> {
+	#[inline(always)]
+	pub fn into_inner(self) -> BitFlags<T> {
+		self.0
+	}
+}
+impl<T: BitFlag> From<BitFlags<T>> for CharFlags<T> {
+	#[inline(always)]
+	fn from(bitflags: BitFlags<T>) -> Self {
+		Self(bitflags)
+	}
+}
+impl<T: BitFlag> Default for CharFlags<T> {
+	#[inline(always)]
+	fn default() -> Self {
+		Self(BitFlags::default())
+	}
+}
+impl<T: BitFlag> Deref for CharFlags<T> {
+	type Target = BitFlags<T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+impl<T: BitFlag> DerefMut for CharFlags<T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+struct CharFlagsVisitor<T>(PhantomData<T>);
+impl<'de, T: BitFlag + Deserialize<'de>> Visitor<'de> for CharFlagsVisitor<T> {
+	type Value = BitFlags<T>;
+
+	fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "a string of flag characters or a sequence of flags")
+	}
+
+	fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+	where
+		E: de::Error,
+	{
+		let mut bitflags = BitFlags::default();
+		for c in s.chars() {
+			let de: de::value::StringDeserializer<E> = c.to_string().into_deserializer();
+			bitflags |= T::deserialize(de)?;
+		}
+		Ok(bitflags)
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		let mut bitflags = BitFlags::default();
+		while let Some(flag) = seq.next_element::<T>()? {
+			bitflags |= flag;
+		}
+		Ok(bitflags)
+	}
+
+	#[inline]
+	fn visit_unit<E>(self) -> Result<Self::Value, E>
+	where
+		E: de::Error,
+	{
+		Ok(BitFlags::default())
+	}
+
+	#[inline]
+	fn visit_none<E>(self) -> Result<Self::Value, E>
+	where
+		E: de::Error,
+	{
+		Ok(BitFlags::default())
+	}
+}
+impl<'de, T: BitFlag + Deserialize<'de>> Deserialize<'de> for CharFlags<T>
+where
+	BitFlags<T>: Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		if deserializer.is_human_readable() {
+			deserializer
+				.deserialize_any(CharFlagsVisitor(PhantomData))
+				.map(Self)
+		} else {
+			BitFlags::deserialize(deserializer).map(Self)
+		}
+	}
+}
+impl<T: BitFlag + Alias> Serialize for CharFlags<T>
+where
+	BitFlags<T>: Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		if serializer.is_human_readable() {
+			serializer.serialize_str(&self.0.iter().map(|flag| flag.alias()).collect::<String>())
+		} else {
+			self.0.serialize(serializer)
+		}
+	}
+}
+impl<T: BitFlag + Alias> Display for CharFlags<T> {
+	/// Concatenate the aliases of the flags, as in the CSV dialect
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.0.iter().try_for_each(|flag| write!(f, "{}", flag.alias()))
+	}
+}
+impl<T: BitFlag + for<'de> Deserialize<'de>> FromStr for CharFlags<T> {
+	type Err = de::value::Error;
+
+	#[inline]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		CharFlagsVisitor(PhantomData).visit_str(s).map(Self)
+	}
+}
+impl<T: BitFlag + Debug> Debug for CharFlags<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+impl<T: BitFlag + Clone> Clone for CharFlags<T> {
+	fn clone(&self) -> Self {
+		Self(self.0)
+	}
+}
+impl<T: BitFlag + Copy> Copy for CharFlags<T> {}
+impl<T: BitFlag + PartialEq> PartialEq for CharFlags<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+impl<T: BitFlag + Eq> Eq for CharFlags<T> {}
+impl<T: BitFlag + PartialEq> PartialEq<BitFlags<T>> for CharFlags<T> {
+	fn eq(&self, other: &BitFlags<T>) -> bool {
+		self.0.eq(other)
+	}
+}
+
+/// [`either::serde_untagged_optional`] for human-readable formats only
+///
+/// Other formats cannot guess which variant is stored, so they use the regular representation.
+pub(crate) mod serde_either_optional {
+	use either::Either;
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	pub fn serialize<L, R, S>(this: &Option<Either<L, R>>, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		L: Serialize,
+		R: Serialize,
+		S: Serializer,
+	{
+		if serializer.is_human_readable() {
+			either::serde_untagged_optional::serialize(this, serializer)
+		} else {
+			this.serialize(serializer)
+		}
+	}
+
+	pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Option<Either<L, R>>, D::Error>
+	where
+		L: Deserialize<'de>,
+		R: Deserialize<'de>,
+		D: Deserializer<'de>,
+	{
+		if deserializer.is_human_readable() {
+			either::serde_untagged_optional::deserialize(deserializer)
+		} else {
+			Option::deserialize(deserializer)
+		}
+	}
+}
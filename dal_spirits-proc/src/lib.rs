@@ -92,9 +92,7 @@ pub fn impl_Display_with_Serialize(input: TokenStream) -> TokenStream {
 			#[automatically_derived]
 			impl std::fmt::Display for #ident {
 				fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-					use wasm_bindgen::UnwrapThrowExt;
-
-					write!(f, "{}", serde_variant::to_variant_name(self).unwrap_throw())
+					write!(f, "{}", serde_variant::to_variant_name(self).map_err(|_err| std::fmt::Error)?)
 				}
 			}
 		}
@@ -166,3 +164,60 @@ pub fn impl_EnumVariantStr(input: TokenStream) -> TokenStream {
 		.into()
 	})
 }
+
+/// Implement `crate::Alias` with the first `#[serde(alias = "...")]` of each variant
+#[allow(non_snake_case)]
+#[proc_macro_derive(EnumAlias)]
+pub fn impl_EnumAlias(input: TokenStream) -> TokenStream {
+	use syn::{Lit, Meta, NestedMeta};
+
+	let ast: DeriveInput = parse_macro_input!(input as DeriveInput);
+	require_enum("EnumAlias", ast, |ast, enum_data| {
+		let ident = &ast.ident;
+		let matching = enum_data
+			.variants
+			.iter()
+			.map(|var| {
+				let ident = &var.ident;
+				var.attrs
+					.iter()
+					.filter(|attr| attr.path.is_ident("serde"))
+					.filter_map(|attr| match attr.parse_meta() {
+						Ok(Meta::List(list)) => Some(list.nested),
+						_ => None,
+					})
+					.flatten()
+					.find_map(|nested| match nested {
+						NestedMeta::Meta(Meta::NameValue(name_value))
+							if name_value.path.is_ident("alias") =>
+						{
+							match name_value.lit {
+								Lit::Str(alias) => Some(alias),
+								_ => None,
+							}
+						}
+						_ => None,
+					})
+					.map(|alias| quote! { Self::#ident => #alias, })
+					.ok_or_else(|| {
+						syn::Error::new(var.span(), "missing #[serde(alias = \"...\")]")
+					})
+			})
+			.collect::<Result<Vec<_>, _>>();
+		match matching {
+			Ok(matching) => quote! {
+				#[automatically_derived]
+				impl crate::Alias for #ident {
+					#[inline]
+					fn alias(&self) -> &'static str {
+						match self {
+							#(#matching)*
+						}
+					}
+				}
+			}
+			.into(),
+			Err(err) => err.into_compile_error().into(),
+		}
+	})
+}
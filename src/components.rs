@@ -1,11 +1,30 @@
-pub(crate) mod app;
+mod app;
+mod birthdays;
+mod chart;
+mod compare;
+mod filters;
 mod home;
+mod loader;
+mod not_found;
+mod sephirot;
+mod sorting;
 mod spirit_details;
+mod spirit_link;
+mod stat_graph;
+mod stats;
 
 const CLASS_TEXTBF: &str = "textbf";
 const CLASS_TEXTIT: &str = "textit";
 const CLASS_TEXTSC: &str = "textsc";
 
-pub(crate) use app::App;
+use app::App;
+use birthdays::Birthdays;
+use compare::{Compare, CompareTray};
+use filters::Filters;
 use home::Home;
+pub(crate) use loader::Loader;
+use not_found::NotFound;
+use sephirot::Sephirot;
+use sorting::Sorting;
 use spirit_details::SpiritDetails;
+use stats::Stats;
@@ -3,18 +3,38 @@ use std::rc::Rc;
 use sycamore::prelude::*;
 use wasm_bindgen::UnwrapThrowExt;
 
-#[component]
-pub(crate) fn App<'a, G: Html>(
+/// Rewrite the URL of an alias to the canonical route of its spirit
+fn redirect<'a, G: Html>(
 	cx: Scope<'a>,
-	data_sig: &'a ReadSignal<Vec<Rc<Spirit>>>,
+	spirit: Option<&Rc<Spirit>>,
+	spoilers_sig: &'a ReadSignal<bool>,
 ) -> View<G> {
+	use crate::router::{self, Route};
+
+	match spirit {
+		Some(spirit) => {
+			router::navigate_replace(&Route::spirit(spirit).path());
+			View::empty()
+		}
+		None => super::NotFound(cx, spoilers_sig),
+	}
+}
+
+#[component]
+pub(super) fn App<G: Html>(cx: Scope) -> View<G> {
 	use super::home::HomeProps;
-	use crate::router::{self, HashRouter, Route};
+	use crate::{
+		router::{Route, Router},
+		store,
+	};
 	use sycamore::builder::prelude::*;
 
+	let store = store::use_store(cx);
 	let spoilers_sig = create_signal(cx, false);
+	// Spirits picked for a comparison, identified by their path segment
+	let tray_sig = create_signal(cx, Vec::new());
 
-	HashRouter(cx, move |cx, route: &ReadSignal<Route>| {
+	Router(cx, move |cx, route: &ReadSignal<Route>| {
 		let node_ref = create_node_ref(cx);
 		h(div)
 			.bind_ref(node_ref.clone())
@@ -26,35 +46,30 @@ pub(crate) fn App<'a, G: Html>(
 				let node: DomNode = node_ref.try_get().unwrap_throw();
 				node.remove_attribute("class");
 				let route = route.get();
-				if let Route::SpiritDetails {
-					name,
-					form: spirit_form,
-				} = route.as_ref()
-				{
-					let data_vec = data_sig.get();
-					if data_vec.len() > 0 {
-						let spirit = data_vec
-							.iter()
-							.find(|spirit| {
-								spirit.firstname.eq(name) && spirit.form_url().eq(spirit_form)
-							})
-							.cloned();
-						if let Some(spirit) = spirit {
-							node.set_class_name(&spirit.firstname);
+				match route.as_ref() {
+					Route::SpiritDetails {
+						chara,
+						form: form_slug,
+					} => {
+						let store = store.get();
+						if let Some(spirit) = store.get(chara, form_slug).cloned() {
+							node.set_class_name(spirit.chara_slug());
 							return super::SpiritDetails(cx, spirit);
 						}
-					} else {
-						return View::empty(); //TODO Loading component (in Home as well?)
+						return redirect(cx, store.resolve(chara, Some(form_slug)), spoilers_sig);
+					}
+					Route::Character { chara } => {
+						return redirect(cx, store.get().resolve(chara, None), spoilers_sig);
 					}
+					Route::Birthdays => return super::Birthdays(cx, spoilers_sig),
+					Route::Stats => return super::Stats(cx, spoilers_sig),
+					Route::Sephirot => return super::Sephirot(cx, spoilers_sig),
+					Route::Compare { spirits } => return super::Compare(cx, spirits.clone()),
+					Route::NotFound => return super::NotFound(cx, spoilers_sig),
+					// Keep the query string, which holds the state of the page
+					Route::Home => {}
 				}
-				router::navigate_replace("/");
-				super::Home(
-					cx,
-					HomeProps {
-						data_sig,
-						spoilers_sig,
-					},
-				)
+				super::Home(cx, HomeProps { spoilers_sig, tray_sig })
 			})
 			.view(cx)
 	})
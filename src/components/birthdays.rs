@@ -0,0 +1,193 @@
+use super::spirit_link::{SpiritLink, SpiritLinkProps};
+use crate::models::{Birthdate, Date, Month, Spirit};
+use std::rc::Rc;
+use sycamore::prelude::*;
+use wasm_bindgen::UnwrapThrowExt;
+
+/// Number of days during which a birthday is highlighted as upcoming
+const UPCOMING_DAYS: u16 = 7;
+
+/// Get the current date in the local time zone
+fn today() -> Date {
+	let now = js_sys::Date::new_0();
+	Month::from_number(now.get_month() as u8 + 1)
+		.and_then(|month| Date::new(now.get_full_year() as i32, month, now.get_date() as u8))
+		.unwrap_throw()
+}
+
+#[component]
+pub(super) fn Birthdays<'a, G: Html>(
+	cx: Scope<'a>,
+	spoilers_sig: &'a ReadSignal<bool>,
+) -> View<G> {
+	use crate::{
+		models::ics,
+		router::{self, Route},
+		store,
+	};
+	use sycamore::builder::prelude::*;
+
+	let today = today();
+	// A single form is enough to represent a character
+	let charas_sig = store::select(cx, move |store| {
+		let mut charas = store
+			.charas()
+			.filter_map(|chara| {
+				store
+					.chara(chara)
+					.find(|spirit| {
+						spirit.birthdate.is_some() && (*spoilers_sig.get() || !spirit.spoiler())
+					})
+					.cloned()
+			})
+			.collect::<Vec<_>>();
+		charas.sort_by_key(|spirit| spirit.birthdate);
+		charas
+	});
+	let ics_href = create_memo(cx, move || {
+		let ics = ics::to_ics(charas_sig.get().iter().map(Rc::as_ref), today);
+		format!(
+			"data:{media_type};charset=utf-8,{ics}",
+			media_type = ics::MEDIA_TYPE,
+			ics = String::from(js_sys::encode_uri_component(&ics))
+		)
+	});
+	let upcoming_sig = create_memo(cx, move || {
+		let mut upcoming = charas_sig
+			.get()
+			.iter()
+			.filter_map(|spirit| {
+				let days = spirit.birthdate?.days_until(today);
+				(days <= UPCOMING_DAYS).then(|| (days, spirit.clone()))
+			})
+			.collect::<Vec<_>>();
+		upcoming.sort_by_key(|(days, _)| *days);
+		upcoming
+	});
+
+	fragment([
+		h(header)
+			.c(h(h1).t("Birthdays"))
+			.c(h(nav)
+				.c(h(a).attr("href", router::href(&Route::Home.path())).t("Back to the list"))
+				.c(h(a)
+					.attr("download", "birthdays.ics")
+					.dyn_attr("href", || Some(ics_href.get().as_ref().clone()))
+					.t("Add to my calendar (.ics)")))
+			.view(cx),
+		h(main)
+			.c(h(section)
+				.id("upcoming")
+				.attr("aria-labelledby", "upcoming-hd")
+				.c(h(h2).id("upcoming-hd").t("Coming up"))
+				.dyn_c(move || {
+					let upcoming = upcoming_sig.get();
+					if upcoming.is_empty() {
+						return h(p)
+							.t("No birthday in the coming week.")
+							.view(cx);
+					}
+					h(ul).c(View::new_fragment(
+						upcoming
+							.iter()
+							.map(|(days, spirit)| {
+								let when = create_ref(
+									cx,
+									match days {
+										0 => "Today".to_string(),
+										1 => "Tomorrow".to_string(),
+										_ => format!("In {days} days"),
+									},
+								);
+								h(li).class(if *days == 0 { "today" } else { "upcoming" })
+									.c(h(span).class(super::CLASS_TEXTBF).t(when))
+									.t(" ")
+									.c(SpiritLink(
+										cx,
+										SpiritLinkProps {
+											spirit: spirit.clone(),
+											short: true,
+										},
+									))
+									.view(cx)
+							})
+							.collect(),
+					))
+					.view(cx)
+				}))
+			.c(View::new_fragment(
+				Month::variants()
+					.into_iter()
+					.map(|month| {
+						MonthCalendar(cx, MonthCalendarProps {
+							month,
+							today,
+							charas_sig,
+						})
+					})
+					.collect(),
+			))
+			.view(cx),
+	])
+}
+
+#[derive(Prop)]
+struct MonthCalendarProps<'a> {
+	month: Month,
+	today: Date,
+	charas_sig: &'a ReadSignal<Vec<Rc<Spirit>>>,
+}
+#[component]
+fn MonthCalendar<'a, G: Html>(cx: Scope<'a>, props: MonthCalendarProps<'a>) -> View<G> {
+	use sycamore::builder::prelude::*;
+
+	let MonthCalendarProps {
+		month,
+		today,
+		charas_sig,
+	} = props;
+	let id = create_ref(cx, format!("month-{month:?}"));
+	let month_txt = create_ref(cx, month.to_string());
+
+	h(section)
+		.class("month")
+		.attr("aria-labelledby", id)
+		.c(h(h2).id(id).t(month_txt))
+		.c(h(ol).dyn_c(move || {
+			let charas = charas_sig.get();
+			View::new_fragment(
+				(1..=month.days())
+					.map(|day| {
+						let birthdate = Birthdate::new(month, day).unwrap_throw();
+						let class = if birthdate.is_today(today) {
+							"today"
+						} else if birthdate.days_until(today) <= UPCOMING_DAYS {
+							"upcoming"
+						} else {
+							""
+						};
+						let day = create_ref(cx, day.to_string());
+						h(li).class(class)
+							.c(h(span).class("day").t(day))
+							.c(View::new_fragment(
+								charas
+									.iter()
+									.filter(|spirit| spirit.birthdate == Some(birthdate))
+									.map(|spirit| {
+										SpiritLink(
+											cx,
+											SpiritLinkProps {
+												spirit: spirit.clone(),
+												short: true,
+											},
+										)
+									})
+									.collect(),
+							))
+							.view(cx)
+					})
+					.collect(),
+			)
+		}))
+		.view(cx)
+}
@@ -0,0 +1,174 @@
+use crate::models::stats::Bin;
+use sycamore::prelude::*;
+
+/// Width of the drawing area of the charts, in user units
+const WIDTH: f32 = 400f32;
+
+/// Bar of a [`BarChart`]
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Bar {
+	pub label: String,
+	/// Classes of the bar, which set its color
+	pub class: String,
+	pub value: f32,
+}
+
+#[derive(Prop)]
+pub(super) struct BarChartProps<'a> {
+	pub bars: &'a [Bar],
+	/// What the values count, such as `spirits`
+	pub unit: &'static str,
+}
+/// Horizontal bars, one per category, labelled on the left
+#[component]
+pub(super) fn BarChart<'a, G: Html>(cx: Scope<'a>, props: BarChartProps<'a>) -> View<G> {
+	use super::stat_graph::format_value;
+	use sycamore::builder::prelude::*;
+
+	const ROW: f32 = 20f32;
+	const GAP: f32 = 4f32;
+	const LABELS: f32 = 120f32;
+	const VALUES: f32 = 40f32;
+	let BarChartProps { bars, unit } = props;
+	let max_value = bars.iter().map(|bar| bar.value).fold(0f32, f32::max);
+
+	h(svg)
+		.class("chart bar-chart")
+		.attr(
+			"viewBox",
+			format!("0 0 {WIDTH} {height}", height = ROW * bars.len() as f32),
+		)
+		.c(View::new_fragment(
+			bars.iter()
+				.enumerate()
+				.map(
+					|(
+						idx,
+						Bar {
+							label: name,
+							class,
+							value,
+						},
+					)| {
+						let y = ROW * idx as f32;
+						let ratio = if max_value > 0f32 {
+							value / max_value
+						} else {
+							0f32
+						};
+						let value = create_ref(cx, format_value(*value));
+						let tooltip = create_ref(cx, format!("{name}: {value} {unit}"));
+
+						h(g).class(format!("bar {class}"))
+							.c(h(title).t(tooltip))
+							.c(h(text)
+								.class("label")
+								.attr("x", (LABELS - GAP).to_string())
+								.attr("y", (y + ROW / 2f32).to_string())
+								.t(name))
+							.c(h(rect)
+								.attr("x", LABELS.to_string())
+								.attr("y", (y + GAP / 2f32).to_string())
+								.attr("width", (ratio * (WIDTH - LABELS - VALUES)).to_string())
+								.attr("height", (ROW - GAP).to_string()))
+							.c(h(text)
+								.class("value")
+								.attr(
+									"x",
+									(LABELS + ratio * (WIDTH - LABELS - VALUES) + GAP).to_string(),
+								)
+								.attr("y", (y + ROW / 2f32).to_string())
+								.t(value))
+							.view(cx)
+					},
+				)
+				.collect(),
+		))
+		.view(cx)
+}
+
+#[derive(Prop)]
+pub(super) struct HistogramProps<'a> {
+	pub bins: &'a [Bin],
+	/// Unit of the values, such as `cm`
+	pub unit: &'static str,
+	/// What the bins count, such as `spirits`
+	pub counted: &'static str,
+}
+/// Adjacent columns, one per bin, with the bounds of the bins on the horizontal axis
+#[component]
+pub(super) fn Histogram<'a, G: Html>(cx: Scope<'a>, props: HistogramProps<'a>) -> View<G> {
+	use super::stat_graph::format_value;
+	use sycamore::builder::prelude::*;
+
+	const HEIGHT: f32 = 200f32;
+	const MARGIN: f32 = 20f32;
+	let HistogramProps {
+		bins,
+		unit,
+		counted,
+	} = props;
+	let max_count = bins.iter().map(|bin| bin.count).max().unwrap_or_default();
+	let column = (WIDTH - 2f32 * MARGIN) / bins.len().max(1) as f32;
+	let plot = HEIGHT - 2f32 * MARGIN;
+	let bound = move |idx: usize, value: f32| {
+		let value = create_ref(cx, format_value(value));
+
+		h(text)
+			.class("bound")
+			.attr("x", (MARGIN + column * idx as f32).to_string())
+			.attr("y", (HEIGHT - MARGIN / 2f32).to_string())
+			.t(value)
+			.view(cx)
+	};
+
+	h(svg)
+		.class("chart histogram")
+		.attr("viewBox", format!("0 0 {WIDTH} {HEIGHT}"))
+		.c(h(line)
+			.class("axis")
+			.attr("x1", MARGIN.to_string())
+			.attr("y1", (HEIGHT - MARGIN).to_string())
+			.attr("x2", (WIDTH - MARGIN).to_string())
+			.attr("y2", (HEIGHT - MARGIN).to_string()))
+		.c(View::new_fragment(
+			bins.iter()
+				.enumerate()
+				.map(|(idx, Bin { start, end, count })| {
+					let ratio = if max_count > 0 {
+						*count as f32 / max_count as f32
+					} else {
+						0f32
+					};
+					let x = MARGIN + column * idx as f32;
+					let y = HEIGHT - MARGIN - ratio * plot;
+					let count = create_ref(cx, count.to_string());
+					let tooltip = create_ref(
+						cx,
+						format!(
+							"{start}–{end} {unit}: {count} {counted}",
+							start = format_value(*start),
+							end = format_value(*end),
+						),
+					);
+
+					h(g).class("column")
+						.c(h(title).t(tooltip))
+						.c(h(rect)
+							.attr("x", x.to_string())
+							.attr("y", y.to_string())
+							.attr("width", column.to_string())
+							.attr("height", (ratio * plot).to_string()))
+						.c(h(text)
+							.class("value")
+							.attr("x", (x + column / 2f32).to_string())
+							.attr("y", (y - 2f32).to_string())
+							.t(count))
+						.c(bound(idx, *start))
+						.view(cx)
+				})
+				.chain(bins.last().map(|last| bound(bins.len(), last.end)))
+				.collect(),
+		))
+		.view(cx)
+}
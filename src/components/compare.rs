@@ -0,0 +1,292 @@
+use crate::models::Spirit;
+use std::rc::Rc;
+use sycamore::prelude::*;
+use wasm_bindgen::UnwrapThrowExt;
+
+/// Maximum number of spirits compared at once
+pub(super) const MAX_SPIRITS: usize = 6;
+
+/// Row of the table of differences, with the value of a spirit in it
+type DiffRow = (&'static str, fn(&Spirit) -> Option<String>);
+
+/// Rows of the table of differences
+const DIFF_ROWS: [DiffRow; 13] = [
+	("Series", |spirit| Some(spirit.series().to_string())),
+	("Birthdate", |spirit| {
+		spirit.birthdate.map(|val| val.to_string())
+	}),
+	("Height", |spirit| {
+		spirit.height.map(|val| format!("{val} cm"))
+	}),
+	("Weight", |spirit| {
+		spirit.weight.map(|val| format!("{val} kg"))
+	}),
+	("B·W·H", |spirit| {
+		spirit
+			.bust
+			.zip(spirit.waist)
+			.zip(spirit.hips)
+			.map(|((bust, waist), hips)| format!("{bust}·{waist}·{hips} cm"))
+	}),
+	("Class", |spirit| spirit.class.map(|val| val.to_string())),
+	("Spacequake class", |spirit| {
+		spirit.spacequake_class.map(|val| val.to_string())
+	}),
+	("Angel class", |spirit| {
+		spirit.angel_class.map(|val| val.to_string())
+	}),
+	("Astral Dress class", |spirit| {
+		spirit.astraldress_class.map(|val| val.to_string())
+	}),
+	("Lore stats total", |spirit| {
+		spirit.lore_total().map(|val| val.to_string())
+	}),
+	("Rank", |spirit| spirit.rank.map(|val| val.to_string())),
+	("Elements", |spirit| {
+		(!spirit.elements.is_empty()).then(|| {
+			spirit
+				.elements
+				.iter()
+				.map(|element| element.to_string())
+				.collect::<Vec<_>>()
+				.join(", ")
+		})
+	}),
+	("Gacha stats total", |spirit| {
+		spirit.gacha_total().map(|val| val.to_string())
+	}),
+];
+
+#[component]
+pub(super) fn Compare<G: Html>(cx: Scope, segments: Vec<String>) -> View<G> {
+	use super::stat_graph::{gacha_axes, lore_axes, StatGraph, StatGraphProps, StatSeries};
+	use crate::{
+		models::{GachaStat, LoreStat},
+		router::{self, Route},
+		store,
+	};
+	use sycamore::builder::prelude::*;
+
+	let store = store::use_store(cx).get();
+	let mut spirits: Vec<Rc<Spirit>> = Vec::new();
+	let mut missing = Vec::new();
+	for segment in segments {
+		let (chara, form_slug) = router::parse_segment(&segment);
+		match store.resolve(chara, form_slug) {
+			Some(spirit) if spirits.iter().any(|other| Rc::ptr_eq(other, spirit)) => {}
+			Some(spirit) => spirits.push(spirit.clone()),
+			None => missing.push(segment),
+		}
+	}
+	spirits.truncate(MAX_SPIRITS);
+	let spirits = create_ref(cx, spirits);
+	let missing = create_ref(cx, missing.join(", "));
+	let lore_series = create_ref(
+		cx,
+		spirits
+			.iter()
+			.filter_map(|spirit| {
+				StatSeries::lore(spirit, spirit.a11y(), spirit.chara_slug().to_string())
+			})
+			.collect::<Vec<_>>(),
+	);
+	let gacha_series = create_ref(
+		cx,
+		spirits
+			.iter()
+			.filter_map(|spirit| {
+				StatSeries::gacha(spirit, spirit.a11y(), spirit.chara_slug().to_string())
+			})
+			.collect::<Vec<_>>(),
+	);
+
+	fragment([
+		h(header)
+			.c(h(h1).t("Comparison"))
+			.c(h(nav).c(h(a)
+				.attr("href", router::href(&Route::Home.path()))
+				.t("Back to the list")))
+			.view(cx),
+		h(main)
+			.dyn_if(
+				|| !missing.is_empty(),
+				|| {
+					h(p).attr("role", "alert")
+						.t("No spirit matches: ")
+						.t(missing.as_str())
+				},
+				View::empty,
+			)
+			.dyn_if(
+				|| spirits.len() < 2,
+				|| {
+					h(p).t("Pick at least two spirits in the compare tray of the list to compare them.")
+				},
+				View::empty,
+			)
+			.c(h(ul).id("compared").c(View::new_fragment(
+				spirits
+					.iter()
+					.map(|spirit| {
+						let href = create_ref(cx, router::href(&Route::spirit(spirit).path()));
+						let remove_href = create_ref(
+							cx,
+							router::href(
+								&Route::compare(
+									spirits
+										.iter()
+										.filter(|other| !Rc::ptr_eq(other, spirit))
+										.map(Rc::as_ref),
+								)
+								.path(),
+							),
+						);
+						let a11y = create_ref(cx, spirit.a11y());
+						let icon = create_ref(cx, spirit.icon());
+
+						h(li)
+							.class(spirit.chara_slug())
+							.c(h(a)
+								.attr("href", href)
+								.dyn_if(
+									|| icon.is_some(),
+									move || {
+										h(img)
+											.attr("src", icon.as_ref().unwrap_throw())
+											.attr("alt", "")
+									},
+									View::empty,
+								)
+								.t(a11y))
+							.c(h(a)
+								.class("remove")
+								.attr("href", remove_href)
+								.attr("aria-label", format!("Remove {a11y} from the comparison"))
+								.t("×"))
+							.view(cx)
+					})
+					.collect(),
+			)))
+			.dyn_if(
+				|| !lore_series.is_empty(),
+				move || {
+					h(section)
+						.id("lore-stats")
+						.attr("aria-labelledby", "lore-stats-hd")
+						.c(h(h2).id("lore-stats-hd").t("Lore stats"))
+						.c(StatGraph(
+							cx,
+							StatGraphProps {
+								axes: create_ref(cx, lore_axes()),
+								series: lore_series,
+								reference: None,
+								max_value: LoreStat::MAX.into(),
+							},
+						))
+				},
+				View::empty,
+			)
+			.dyn_if(
+				|| !gacha_series.is_empty(),
+				move || {
+					h(section)
+						.id("gacha-stats")
+						.attr("aria-labelledby", "gacha-stats-hd")
+						.c(h(h2).id("gacha-stats-hd").t("Gacha stats"))
+						.c(StatGraph(
+							cx,
+							StatGraphProps {
+								axes: create_ref(cx, gacha_axes()),
+								series: gacha_series,
+								reference: None,
+								max_value: GachaStat::MAX.into(),
+							},
+						))
+				},
+				View::empty,
+			)
+			.c(h(section)
+				.id("differences")
+				.attr("aria-labelledby", "differences-hd")
+				.c(h(h2).id("differences-hd").t("Differences"))
+				.c(h(table)
+					.c(h(thead).c(h(tr).c(h(td)).c(View::new_fragment(
+						spirits
+							.iter()
+							.map(|spirit| {
+								let a11y = create_ref(cx, spirit.a11y());
+								h(th).attr("scope", "col").t(a11y).view(cx)
+							})
+							.collect(),
+					))))
+					.c(h(tbody).c(View::new_fragment(
+						DIFF_ROWS
+							.into_iter()
+							.map(|(name, get)| {
+								let values =
+									spirits.iter().map(|spirit| get(spirit)).collect::<Vec<_>>();
+								let differs = values.windows(2).any(|pair| pair[0] != pair[1]);
+
+								h(tr)
+									.class(if differs { "differs" } else { "same" })
+									.c(h(th).attr("scope", "row").t(name))
+									.c(View::new_fragment(
+										values
+											.into_iter()
+											.map(|value| match value {
+												Some(value) => {
+													let value = create_ref(cx, value);
+													h(td).t(value).view(cx)
+												}
+												None => h(td).c(h(i).t("Unknown")).view(cx),
+											})
+											.collect(),
+									))
+									.view(cx)
+							})
+							.collect(),
+					)))))
+			.view(cx),
+	])
+}
+
+/// Bar of the spirits picked for a comparison
+#[component]
+pub(super) fn CompareTray<'a, G: Html>(
+	cx: Scope<'a>,
+	tray_sig: &'a Signal<Vec<String>>,
+) -> View<G> {
+	use crate::router::{self, Route};
+	use sycamore::builder::prelude::*;
+
+	let href = create_memo(cx, || {
+		router::href(
+			&Route::Compare {
+				spirits: tray_sig.get().as_ref().clone(),
+			}
+			.path(),
+		)
+	});
+
+	h(aside)
+		.id("compare-tray")
+		.attr("aria-label", "Compare tray")
+		.dyn_attr("hidden", || tray_sig.get().is_empty().then_some(""))
+		.c(h(span).dyn_t(move || {
+			format!(
+				"{count} / {MAX_SPIRITS} spirits picked",
+				count = tray_sig.get().len()
+			)
+		}))
+		.c(h(a)
+			.dyn_attr("href", || Some(href.get().as_ref().clone()))
+			.dyn_attr("aria-disabled", || {
+				(tray_sig.get().len() < 2).then_some("true")
+			})
+			.t("Compare"))
+		.c(h(button)
+			.attr("type", "button")
+			.on("click", |_| tray_sig.set(Vec::new()))
+			.t("Clear"))
+		.view(cx)
+}
@@ -0,0 +1,138 @@
+use crate::{models::filter::Filter, router::HomeParams};
+use sycamore::prelude::*;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::{Event, HtmlInputElement, HtmlSelectElement};
+
+/// Apply a new filter in a new history entry
+fn set_filter(params_sig: &ReadSignal<HomeParams>, filter: Filter) {
+	use crate::router::{self, Route};
+
+	let params = HomeParams {
+		filter,
+		..params_sig.get().as_ref().clone()
+	};
+	router::navigate_push(&Route::Home.with_query(&params));
+}
+
+#[inline]
+fn select_value(ev: &Event) -> String {
+	ev.target()
+		.unwrap_throw()
+		.unchecked_into::<HtmlSelectElement>()
+		.value()
+}
+
+/// Build a `<select>` choosing an optional value, the empty value standing for any
+macro_rules! select_any {
+	($cx:expr, $params_sig:expr, $label:literal, $values:expr, $field:ident) => {{
+		let params_sig = $params_sig;
+		h(label)
+			.t($label)
+			.c(h(select)
+				.c(h(option).attr("value", "").t("Any"))
+				.c(View::new_fragment(
+					$values
+						.into_iter()
+						.map(|value| {
+							let value = create_ref($cx, value.to_string());
+							h(option).attr("value", value.as_str()).t(value).view($cx)
+						})
+						.collect(),
+				))
+				.dyn_prop("value", move || {
+					params_sig
+						.get()
+						.filter
+						.$field
+						.map(|value| value.to_string())
+						.unwrap_or_default()
+				})
+				.on("change", move |ev: Event| {
+					let mut new_filter = params_sig.get().filter;
+					new_filter.$field = select_value(&ev).parse().ok();
+					set_filter(params_sig, new_filter);
+				}))
+	}};
+}
+
+/// Build checkboxes toggling flags
+macro_rules! checkboxes {
+	($cx:expr, $params_sig:expr, $legend:literal, $flags:expr, $field:ident) => {{
+		let params_sig = $params_sig;
+		h(fieldset)
+			.c(h(legend).t($legend))
+			.c(View::new_fragment(
+				$flags
+					.into_iter()
+					.map(|flag| {
+						let name = create_ref($cx, flag.to_string());
+						h(label)
+							.c(h(input)
+								.attr("type", "checkbox")
+								.dyn_prop("checked", move || params_sig.get().filter.$field.contains(flag))
+								.on("change", move |ev: Event| {
+									let mut new_filter = params_sig.get().filter;
+									let checked = ev
+										.target()
+										.unwrap_throw()
+										.unchecked_into::<HtmlInputElement>()
+										.checked();
+									if checked {
+										new_filter.$field.insert(flag);
+									} else {
+										new_filter.$field.remove(flag);
+									}
+									set_filter(params_sig, new_filter);
+								}))
+							.t(name)
+							.view($cx)
+					})
+					.collect(),
+			))
+	}};
+}
+
+#[component]
+pub(super) fn Filters<'a, G: Html>(
+	cx: Scope<'a>,
+	params_sig: &'a ReadSignal<HomeParams>,
+) -> View<G> {
+	use crate::models::{Class, Element, Gender, Medium};
+	use sycamore::builder::prelude::*;
+
+	h(details)
+		.id("filters")
+		.c(h(summary).t("Filters"))
+		.c(checkboxes!(cx, params_sig, "Elements", Element::variants(), elements))
+		.c(checkboxes!(cx, params_sig, "Media", Medium::variants(), media))
+		.c(h(fieldset)
+			.c(h(legend).t("Characteristics"))
+			.c(select_any!(cx, params_sig, "Lowest class ", Class::variants(), min_class))
+			.c(select_any!(cx, params_sig, "Lowest rank ", Class::variants(), min_rank))
+			.c(select_any!(cx, params_sig, "Gender ", Gender::variants(), gender))
+			.c(h(label)
+				.t("Series ")
+				.c(h(select)
+					.c(h(option).attr("value", "").t("Any"))
+					.c(h(option).attr("value", "false").t("'Date A Live' lore"))
+					.c(h(option).attr("value", "true").t("Collaborations"))
+					.dyn_prop("value", || {
+						params_sig
+							.get()
+							.filter
+							.collab
+							.map(|collab| collab.to_string())
+							.unwrap_or_default()
+					})
+					.on("change", |ev: Event| {
+						let mut new_filter = params_sig.get().filter;
+						new_filter.collab = select_value(&ev).parse().ok();
+						set_filter(params_sig, new_filter);
+					}))))
+		.c(h(button)
+			.attr("type", "button")
+			.dyn_bool_attr("disabled", || params_sig.get().filter.is_empty())
+			.on("click", |_| set_filter(params_sig, Filter::default()))
+			.t("Clear filters"))
+		.view(cx)
+}
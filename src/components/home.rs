@@ -1,23 +1,50 @@
-use crate::models::{Series, Spirit};
+use crate::models::{filter::Filter, group::Group, search::Query, sort::Sort, Spirit};
 use std::rc::Rc;
 use sycamore::prelude::*;
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
 
+/// State of the navigation shared by its components
+#[derive(Clone, Copy)]
+struct NavState<'a> {
+	spoilers_sig: &'a ReadSignal<bool>,
+	query_sig: &'a ReadSignal<Query>,
+	filter_sig: &'a ReadSignal<Filter>,
+	sort_sig: &'a ReadSignal<Option<Sort>>,
+	tray_sig: &'a Signal<Vec<String>>,
+}
+impl NavState<'_> {
+	/// Check whether a spirit is listed, regardless of the search query
+	#[inline]
+	fn is_listed(&self, spirit: &Spirit) -> bool {
+		(*self.spoilers_sig.get() || !spirit.spoiler()) && self.filter_sig.get().matches(spirit)
+	}
+}
+
 #[derive(Prop)]
 pub(super) struct HomeProps<'a> {
-	pub data_sig: &'a ReadSignal<Vec<Rc<Spirit>>>,
 	pub spoilers_sig: &'a Signal<bool>,
+	pub tray_sig: &'a Signal<Vec<String>>,
 }
 #[component]
 pub(super) fn Home<'a, G: Html>(cx: Scope<'a>, props: HomeProps<'a>) -> View<G> {
+	use crate::{
+		data::{self, FileSource},
+		router::{self, HomeParams, Route},
+	};
 	use sycamore::builder::prelude::*;
-
 	use web_sys::{Event, HtmlInputElement};
 
-	let HomeProps {
-		data_sig,
+	let HomeProps { spoilers_sig, tray_sig } = props;
+	let params_sig = router::use_query_params::<HomeParams>(cx);
+	let nav_state = NavState {
 		spoilers_sig,
-	} = props;
+		query_sig: create_memo(cx, || Query::new(&params_sig.get().search)),
+		filter_sig: create_selector(cx, || params_sig.get().filter),
+		sort_sig: create_selector(cx, || params_sig.get().sort),
+		tray_sig,
+	};
+	let flat_sig = create_selector(cx, || params_sig.get().flat);
+	let group_by_sig = create_selector(cx, || params_sig.get().group);
 
 	fragment([
 		h(header).attr("role", "banner")
@@ -29,28 +56,43 @@ pub(super) fn Home<'a, G: Html>(cx: Scope<'a>, props: HomeProps<'a>) -> View<G>
 				.attr("alt", "'Date A Live' Spirits"))
 			.view(cx),
 		h(main)
-			.c(h(nav).attr("aria-label", "Spirits").c(View::new_fragment(
-				Series::variants()
-					.into_iter()
-					.map(|series| {
-						let data_sig = data_sig.map(cx, move |spirits| {
-							spirits
-								.iter()
-								.filter(|spirit| {
-									spirit.series() == series
-										&& (*spoilers_sig.get() || !spirit.spoiler())
-								})
-								.cloned()
-								.collect()
-						});
-						NavSeries(cx, NavSeriesProps { series, data_sig })
-					})
-					.collect(),
-			)))
+			.c(h(input)
+				.attr("type", "search")
+				.id("search")
+				.attr("aria-label", "Search spirits")
+				.attr("placeholder", "Search by name, form, Sephira, Angel or Astral Dress…")
+				.dyn_prop("value", || params_sig.get().search.clone())
+				.on("input", |ev: Event| {
+					// Typing must not flood the history
+					let params = HomeParams {
+						search: ev.target().unwrap_throw().unchecked_into::<HtmlInputElement>().value(),
+						..params_sig.get().as_ref().clone()
+					};
+					router::navigate_replace(&Route::Home.with_query(&params));
+				}))
+			.c(super::Filters(cx, params_sig))
+			.c(super::Sorting(cx, params_sig))
+			.c(h(nav).attr("aria-label", "Spirits").dyn_c(move || {
+				if *flat_sig.get() {
+					NavFlat(cx, nav_state)
+				} else {
+					View::new_fragment(
+						group_by_sig
+							.get()
+							.groups()
+							.into_iter()
+							.map(|group| NavGroup(cx, NavGroupProps { group, nav_state }))
+							.collect(),
+					)
+				}
+			}))
 			.c(h(hr))
 			.c(h(div)
 				.attr("aria-label", "Navigation options")
 				.id("nav-options")
+				.c(h(a).attr("href", router::href(&Route::Birthdays.path())).t("Birthday calendar"))
+				.c(h(a).attr("href", router::href(&Route::Stats.path())).t("Statistics"))
+				.c(h(a).attr("href", router::href(&Route::Sephirot.path())).t("Tree of Life"))
 				.c(h(label)
 					.c(h(input).attr("type", "checkbox").on("input", |ev: Event| {
 						spoilers_sig.set(!*spoilers_sig.get() && web_sys::window()
@@ -59,67 +101,94 @@ pub(super) fn Home<'a, G: Html>(cx: Scope<'a>, props: HomeProps<'a>) -> View<G>
 							.unwrap_or_default());
 						ev.target().unwrap_throw().unchecked_into::<HtmlInputElement>().set_checked(*spoilers_sig.get());
 					}))
-					.t("Enable spoilers")))
+					.t("Enable spoilers"))
+				.c(h(label)
+					.c(h(input)
+						.attr("type", "file")
+						.attr("accept", ".csv,text/csv,.json,application/json")
+						.on("change", move |ev: Event| {
+							let file = ev
+								.target()
+								.unwrap_throw()
+								.unchecked_into::<HtmlInputElement>()
+								.files()
+								.and_then(|files| files.get(0));
+							if let Some(file) = file {
+								data::use_source(cx).set(FileSource::new(file).into());
+							}
+						}))
+					.t("Load a dataset file")))
+			.c(super::CompareTray(cx, tray_sig))
 			.view(cx)
 	])
 }
 
 #[derive(Prop)]
-struct NavSeriesProps<'a> {
-	series: Series,
-	data_sig: &'a ReadSignal<Vec<Rc<Spirit>>>,
+struct NavGroupProps<'a> {
+	group: Group,
+	nav_state: NavState<'a>,
 }
 #[component]
-fn NavSeries<'a, G: Html>(cx: Scope<'a>, props: NavSeriesProps<'a>) -> View<G> {
-	use std::collections::HashMap;
+fn NavGroup<'a, G: Html>(cx: Scope<'a>, props: NavGroupProps<'a>) -> View<G> {
+	use crate::store;
 	use sycamore::{builder::prelude::*, component::Prop};
 
-	let NavSeriesProps { series, data_sig } = props;
-	let id = create_ref(cx, format!("{series:?}"));
-	let series_txt = create_ref(cx, series.to_string());
-	let split_data_sig = create_signal(cx, HashMap::new());
-	let chara_order_sig = create_signal(cx, Vec::new());
-	create_effect(cx, move || {
-		let mut split_data_sig = split_data_sig.modify();
-		split_data_sig
-			.values_mut()
-			.for_each(|vec_sig: &mut &Signal<Vec<Rc<Spirit>>>| {
-				vec_sig.modify().clear();
+	let NavGroupProps { group, nav_state } = props;
+	let id = create_ref(cx, group.id());
+	let group_txt = create_ref(cx, group.name());
+	let charas_sig = store::select(cx, move |store| {
+		let query = nav_state.query_sig.get();
+		let sort = *nav_state.sort_sig.get();
+		// Best score and first form in order of each character, by order of first appearance
+		let mut charas: Vec<(String, Option<u32>, &Rc<Spirit>)> = Vec::new();
+		let members: Box<dyn Iterator<Item = &Rc<Spirit>> + '_> = match group {
+			Group::Series(series) => Box::new(store.series(series)),
+			Group::Element(element) => Box::new(store.element(element)),
+			Group::Medium(medium) => Box::new(store.medium(medium)),
+			_ => Box::new(store.all().iter().filter(move |spirit| group.contains(spirit))),
+		};
+		members
+			.filter(|spirit| nav_state.is_listed(spirit))
+			.for_each(|spirit| {
+				let score = if query.is_empty() {
+					Some(0)
+				} else {
+					query.score(spirit)
+				};
+				match charas
+					.iter_mut()
+					.find(|(chara, _, _)| chara == spirit.chara_slug())
+				{
+					Some((_, best, first)) => {
+						*best = match (*best, score) {
+							(Some(best), Some(score)) => Some(best.min(score)),
+							(best, score) => best.or(score),
+						};
+						if sort.map_or(false, |sort| sort.compare(spirit, first).is_lt()) {
+							*first = spirit;
+						}
+					}
+					None => charas.push((spirit.chara_slug().to_string(), score, spirit)),
+				}
 			});
-		let mut chara_order_sig = chara_order_sig.modify();
-		chara_order_sig.clear();
-		data_sig.get().iter().for_each(|spirit| {
-			let k = (spirit.firstname.clone(), spirit.lastname.clone());
-			if !chara_order_sig.contains(&k) {
-				chara_order_sig.push(k.clone());
-			}
-			if !split_data_sig.contains_key(&k) {
-				split_data_sig.insert(k.clone(), create_signal(cx, Vec::new()));
-			}
-			split_data_sig
-				.get_mut(&k)
-				.unwrap_throw()
-				.modify()
-				.push(spirit.clone());
-		});
-	});
-	let split_data_sig = create_memo(cx, || {
-		chara_order_sig
-			.get()
-			.iter()
-			.filter_map(|(firstname, lastname)| {
-				let k = (firstname.clone(), lastname.clone());
-				split_data_sig.get().get(&k).map(|sig| (k, *sig))
-			})
+		charas.retain(|(_, score, _)| score.is_some());
+		match sort {
+			Some(sort) => charas.sort_by(|(_, _, left), (_, _, right)| sort.compare(left, right)),
+			None => charas.sort_by_key(|(_, score, _)| *score),
+		}
+		charas
+			.into_iter()
+			.map(|(chara, _, _)| chara)
 			.collect::<Vec<_>>()
 	});
 
 	h(div)
 		.attr("aria-labelledby", id)
-		.class("series")
+		.class("group")
+		.dyn_attr("hidden", || charas_sig.get().is_empty().then_some(""))
 		.dyn_c(move || {
-			let el = h(h2).attr("id", id).t(series_txt);
-			if series.is_collab() {
+			let el = h(h2).attr("id", id).t(group_txt);
+			if matches!(group, Group::Series(series) if series.is_collab()) {
 				el.c(h(span).t("COLLAB").attr("title", "These characters are not from the 'Date A Live' lore. They were added in the 'Spirit Pledge' game during collaboration events.")).view(cx)
 			} else {
 				el.view(cx)
@@ -127,13 +196,13 @@ fn NavSeries<'a, G: Html>(cx: Scope<'a>, props: NavSeriesProps<'a>) -> View<G> {
 		})
 		.dyn_c(move || {
 			let props = KeyedProps::builder()
-				.iterable(split_data_sig)
-				.key(|(k, _)| k.clone())
-				.view(|cx, ((firstname, lastname), data_sig)| {
+				.iterable(charas_sig)
+				.key(Clone::clone)
+				.view(move |cx, chara_slug| {
 					NavChara(cx, NavCharaProps {
-						firstname,
-						lastname,
-						data_sig,
+						chara_slug,
+						group,
+						nav_state,
 					})
 				})
 				.build();
@@ -144,69 +213,209 @@ fn NavSeries<'a, G: Html>(cx: Scope<'a>, props: NavSeriesProps<'a>) -> View<G> {
 
 #[derive(Prop)]
 struct NavCharaProps<'a> {
-	firstname: String,
-	lastname: Option<String>,
-	data_sig: &'a ReadSignal<Vec<Rc<Spirit>>>,
+	chara_slug: String,
+	group: Group,
+	nav_state: NavState<'a>,
 }
 #[component]
 fn NavChara<'a, G: Html>(cx: Scope<'a>, props: NavCharaProps<'a>) -> View<G> {
+	use crate::store;
 	use sycamore::{builder::prelude::*, component::Prop};
 
 	let NavCharaProps {
-		firstname,
-		lastname,
-		data_sig,
+		chara_slug,
+		group,
+		nav_state,
 	} = props;
+	let chara_slug = create_ref(cx, chara_slug);
+	let data_sig = store::select(cx, move |store| {
+		let mut forms = store
+			.chara(chara_slug)
+			.filter(|spirit| group.contains(spirit) && nav_state.is_listed(spirit))
+			.cloned()
+			.collect::<Vec<_>>();
+		if let Some(sort) = *nav_state.sort_sig.get() {
+			forms.sort_by(|left, right| sort.compare(left, right));
+		}
+		forms
+	});
+	// All the forms of a character share the same names
+	let (firstname, lastname) = data_sig
+		.get_untracked()
+		.first()
+		.map(|spirit| (spirit.firstname.clone(), spirit.lastname.clone()))
+		.unwrap_or_default();
 	let firstname = create_ref(cx, firstname);
 	let lastname = create_ref(cx, lastname.unwrap_or_default());
-	let class = create_ref(cx, format!("chara {firstname}"));
+	let id = create_ref(cx, format!("{group}-chara-{chara_slug}", group = group.id()));
+	let class = create_ref(cx, format!("chara {chara_slug}"));
 
 	h(div)
-		.attr("aria-labelledby", firstname)
+		.attr("aria-labelledby", id)
 		.class(class)
 		.c(h(h3)
-			.attr("id", firstname)
+			.attr("id", id)
 			.t(firstname)
 			.t(" ")
 			.c(h(span).class(super::CLASS_TEXTSC).t(lastname)))
 		.c(h(div).dyn_c(move || {
 			let props = KeyedProps::builder()
 				.iterable(data_sig)
-				.key(|spirit| spirit.form.clone())
-				.view(|cx, spirit| NavItem(cx, spirit))
+				.key(|spirit| spirit.form_slug().to_string())
+				.view(move |cx, item| {
+					NavItem(cx, NavItemProps {
+						item,
+						nav_state,
+						with_name: false,
+					})
+				})
 				.build();
 			Keyed(cx, props)
 		}))
 		.view(cx)
 }
 
+/// Single list of the spirits, ranked by the search query or by the sort
 #[component]
-fn NavItem<G: Html>(cx: Scope, item: Rc<Spirit>) -> View<G> {
+fn NavFlat<'a, G: Html>(cx: Scope<'a>, nav_state: NavState<'a>) -> View<G> {
+	use crate::{router::Route, store};
+	use sycamore::{builder::prelude::*, component::Prop};
+
+	let spirits_sig = store::select(cx, move |store| {
+		let query = nav_state.query_sig.get();
+		let mut spirits = store
+			.all()
+			.iter()
+			.filter(|spirit| nav_state.is_listed(spirit))
+			.filter_map(|spirit| {
+				let score = if query.is_empty() {
+					Some(0)
+				} else {
+					query.score(spirit)
+				};
+				score.map(|score| (score, spirit.clone()))
+			})
+			.collect::<Vec<_>>();
+		match *nav_state.sort_sig.get() {
+			Some(sort) => spirits.sort_by(|(_, left), (_, right)| sort.compare(left, right)),
+			None => spirits.sort_by_key(|(score, _)| *score),
+		}
+		spirits
+			.into_iter()
+			.map(|(_, spirit)| spirit)
+			.collect::<Vec<_>>()
+	});
+
+	h(ol).class("flat")
+		.dyn_c(move || {
+			let props = KeyedProps::builder()
+				.iterable(spirits_sig)
+				.key(|spirit| Route::spirit(spirit).path())
+				.view(move |cx, item| {
+					let class = create_ref(cx, format!("chara {}", item.chara_slug()));
+					h(li).class(class).c(NavItem(cx, NavItemProps {
+						item,
+						nav_state,
+						with_name: true,
+					}))
+					.view(cx)
+				})
+				.build();
+			Keyed(cx, props)
+		})
+		.view(cx)
+}
+
+#[derive(Prop)]
+struct NavItemProps<'a> {
+	item: Rc<Spirit>,
+	nav_state: NavState<'a>,
+	/// Whether the name of the character is shown along the form, with the sorted value
+	with_name: bool,
+}
+#[component]
+fn NavItem<'a, G: Html>(cx: Scope<'a>, props: NavItemProps<'a>) -> View<G> {
+	use super::compare::MAX_SPIRITS;
+	use crate::router::{self, Route};
 	use sycamore::builder::prelude::*;
+	use web_sys::{Event, HtmlInputElement};
+
+	let NavItemProps {
+		item,
+		nav_state,
+		with_name,
+	} = props;
 
-	let href = create_ref(
-		cx,
-		format!(
-			"#/spirits/{name}/{form}",
-			name = item.firstname,
-			form = item.form_url()
-		),
-	);
+	let href = create_ref(cx, router::href(&Route::spirit(&item).path()));
 	let spirit_form = create_ref(cx, item.form.clone());
+	let firstname = create_ref(cx, with_name.then(|| item.firstname.clone()));
 	let a11y = create_ref(cx, item.a11y());
 	let icon = create_ref(cx, item.icon());
+	let segment = create_ref(cx, router::spirit_segment(&item));
+	let item = create_ref(cx, item);
+	let picked_sig = create_selector(cx, || nav_state.tray_sig.get().contains(segment));
 
-	h(a).attr("href", href)
-		.attr("aria-label", a11y)
-		.c(h(p).t(spirit_form))
-		.dyn_if(
-			|| icon.is_some(),
-			move || {
-				h(img)
-					.attr("src", icon.as_ref().unwrap_throw())
-					.attr("alt", format!("{a11y} face"))
-			},
-			|| h(div).class("icon-placeholder"),
-		)
+	h(div)
+		.class("item")
+		.c(h(a)
+			.attr("href", href)
+			.attr("aria-label", a11y)
+			.dyn_class("match", move || {
+				let query = nav_state.query_sig.get();
+				!query.is_empty() && query.score(item).is_some()
+			})
+			.c(h(p)
+				.t(spirit_form)
+				.dyn_if(
+					|| firstname.is_some(),
+					|| h(span).class("name").t(firstname.as_deref().unwrap_throw()),
+					View::empty,
+				)
+				.dyn_c(move || {
+					let value = nav_state
+						.sort_sig
+						.get()
+						.filter(|_| with_name)
+						.and_then(|sort| sort.key.value(item));
+					match value {
+						Some(value) => {
+							let value = create_ref(cx, value);
+							h(span).class("value").t(value).view(cx)
+						}
+						None => View::empty(),
+					}
+				}))
+			.dyn_if(
+				|| icon.is_some(),
+				move || {
+					h(img)
+						.attr("src", icon.as_ref().unwrap_throw())
+						.attr("alt", format!("{a11y} face"))
+				},
+				|| h(div).class("icon-placeholder"),
+			))
+		.c(h(label)
+			.class("compare")
+			.attr("title", "Compare")
+			.c(h(input)
+				.attr("type", "checkbox")
+				.attr("aria-label", format!("Compare {a11y}"))
+				.dyn_prop("checked", || *picked_sig.get())
+				.dyn_bool_attr("disabled", || {
+					!*picked_sig.get() && nav_state.tray_sig.get().len() >= MAX_SPIRITS
+				})
+				.on("change", move |ev: Event| {
+					let checked = ev
+						.target()
+						.unwrap_throw()
+						.unchecked_into::<HtmlInputElement>()
+						.checked();
+					let mut tray = nav_state.tray_sig.get().as_ref().clone();
+					tray.retain(|other| other != segment);
+					if checked {
+						tray.push(segment.clone());
+					}
+					nav_state.tray_sig.set(tray);
+				})))
 		.view(cx)
 }
@@ -0,0 +1,86 @@
+use crate::{data::Source, models::Error};
+use sycamore::prelude::*;
+
+/// Load the requested dataset, then render the application
+///
+/// Any new request made through [`use_source`](crate::data::use_source) reloads the data,
+/// in a new scope so that the previous application is cleaned up first.
+#[component]
+pub(crate) fn Loader<G: Html>(cx: Scope) -> View<G> {
+	use crate::data;
+	use sycamore::{
+		component::{Children, Prop},
+		suspense::{Suspense, SuspenseProps},
+	};
+
+	let source_sig = data::use_source(cx);
+
+	View::new_dyn_scoped(cx, move |cx| {
+		let source = source_sig.get().as_ref().clone();
+		let props = SuspenseProps::builder()
+			.fallback(Loading(cx, source.to_string()))
+			.children(Children::new(cx, move |cx| Load(cx, source)))
+			.build();
+		Suspense(cx, props)
+	})
+}
+
+#[component]
+async fn Load<G: Html>(cx: Scope<'_>, source: Source) -> View<G> {
+	use crate::{data, store};
+
+	match data::load(store::use_store(cx), &source).await {
+		Ok(()) => super::App(cx, ()),
+		Err(err) => {
+			web_sys::console::error_1(&err.to_string().into());
+			LoadError(cx, LoadErrorProps { source, err })
+		}
+	}
+}
+
+#[component]
+fn Loading<G: Html>(cx: Scope, origin: String) -> View<G> {
+	use sycamore::builder::prelude::*;
+
+	let origin = create_ref(cx, origin);
+
+	h(div)
+		.id("Loading")
+		.attr("role", "status")
+		.c(h(div).class("spinner"))
+		.c(h(p).t("Loading character data from ").t(origin).t("…"))
+		.view(cx)
+}
+
+#[derive(Prop)]
+struct LoadErrorProps {
+	source: Source,
+	err: Error,
+}
+#[component]
+fn LoadError<G: Html>(cx: Scope, props: LoadErrorProps) -> View<G> {
+	use crate::data;
+	use sycamore::builder::prelude::*;
+
+	let LoadErrorProps {
+		source: origin,
+		err,
+	} = props;
+	let heading = create_ref(
+		cx,
+		format!("Could not load the character data from {origin}."),
+	);
+	let message = create_ref(cx, err.to_string());
+	let origin = create_ref(cx, origin);
+
+	h(div)
+		.id("Error")
+		.attr("role", "alert")
+		.c(h(h1).t(heading))
+		.c(h(pre).t(message))
+		.c(h(button)
+			.attr("type", "button")
+			.on("click", move |_| data::use_source(cx).set(origin.clone()))
+			.t("Retry"))
+		.view(cx)
+}
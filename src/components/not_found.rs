@@ -0,0 +1,127 @@
+use sycamore::prelude::*;
+
+/// Maximum number of suggested spirits
+const SUGGESTIONS: usize = 5;
+
+/// Get the character and form slugs that a path was likely meant to hold
+///
+/// The first segment is skipped if it names a route.
+fn guess_slugs(path: &str) -> Option<(String, Option<String>)> {
+	use crate::router::{self, Route};
+
+	let first_segment = |route: &Route| {
+		route
+			.path()
+			.split('/')
+			.find(|segment| !segment.is_empty())
+			.map(str::to_string)
+	};
+	let compare = first_segment(&Route::Compare {
+		spirits: Vec::new(),
+	});
+	let prefixes = [
+		Route::Character {
+			chara: String::new(),
+		},
+		Route::Birthdays,
+		Route::Stats,
+		Route::Sephirot,
+	]
+	.map(|route| first_segment(&route));
+
+	let mut segments = path
+		.split('/')
+		.filter(|segment| !segment.is_empty())
+		.map(|segment| {
+			js_sys::decode_uri_component(segment)
+				.map(String::from)
+				.unwrap_or_else(|_| segment.to_string())
+		})
+		.peekable();
+	match segments.peek() {
+		Some(prefix) if Some(prefix) == compare.as_ref() => {
+			// Compared spirits are each held in a single segment
+			segments.next();
+			let segment = segments.next()?;
+			let (chara, form) = router::parse_segment(&segment);
+			return Some((chara.to_string(), form.map(str::to_string)));
+		}
+		Some(prefix) if prefixes.iter().flatten().any(|first| first == prefix) => {
+			segments.next();
+		}
+		_ => {}
+	}
+	let chara = segments.next()?;
+	Some((chara, segments.next()))
+}
+
+#[component]
+pub(super) fn NotFound<'a, G: Html>(
+	cx: Scope<'a>,
+	spoilers_sig: &'a ReadSignal<bool>,
+) -> View<G> {
+	use super::spirit_link::{SpiritLink, SpiritLinkProps};
+	use crate::{
+		models::search,
+		router::{self, Route},
+		store,
+	};
+	use sycamore::builder::prelude::*;
+
+	let path_sig = router::use_path(cx);
+	let suggestions_sig = store::select(cx, move |store| {
+		guess_slugs(&path_sig.get())
+			.map(|(chara, form_slug)| {
+				search::suggest(
+					store
+						.all()
+						.iter()
+						.filter(|spirit| *spoilers_sig.get() || !spirit.spoiler())
+						.cloned(),
+					&chara,
+					form_slug.as_deref(),
+					SUGGESTIONS,
+				)
+			})
+			.unwrap_or_default()
+	});
+
+	fragment([
+		h(header)
+			.c(h(h1).t("Page not found"))
+			.c(h(nav).c(h(a).attr("href", router::href(&Route::Home.path())).t("Back to the list")))
+			.view(cx),
+		h(main)
+			.c(h(p)
+				.t("Nothing could be found at ")
+				.c(h(code).dyn_t(|| path_sig.get().as_ref().clone()))
+				.t("."))
+			.dyn_c(move || {
+				let suggestions = suggestions_sig.get();
+				if suggestions.is_empty() {
+					return View::empty();
+				}
+				fragment([
+					h(p).t("Did you mean:").view(cx),
+					h(ul).c(View::new_fragment(
+						suggestions
+							.iter()
+							.map(|spirit| {
+								h(li)
+									.c(SpiritLink(
+										cx,
+										SpiritLinkProps {
+											spirit: spirit.clone(),
+											short: false,
+										},
+									))
+									.view(cx)
+							})
+							.collect(),
+					))
+					.view(cx),
+				])
+			})
+			.view(cx),
+	])
+}
@@ -0,0 +1,419 @@
+use crate::{
+	models::{Attribute, Sephira, SephiraWording, Spirit},
+	store::SpiritStore,
+};
+use std::rc::Rc;
+use sycamore::prelude::*;
+use wasm_bindgen::UnwrapThrowExt;
+
+/// Sephirot of the Tree of Life, with their traditional positions
+///
+/// Columns go from the left pillar (`-1`) to the right one (`1`), rows from the top.
+const SEPHIROT: [(&str, f32, f32); 10] = [
+	("Kether", 0f32, 0f32),
+	("Chokhmah", 1f32, 1f32),
+	("Binah", -1f32, 1f32),
+	("Chesed", 1f32, 2.5),
+	("Geburah", -1f32, 2.5),
+	("Tiphareth", 0f32, 3.5),
+	("Netzach", 1f32, 4.5),
+	("Hod", -1f32, 4.5),
+	("Yesod", 0f32, 5.5),
+	("Malkuth", 0f32, 7f32),
+];
+
+/// The 22 paths between the [`SEPHIROT`], by their indices
+const PATHS: [(usize, usize); 22] = [
+	(0, 1),
+	(0, 2),
+	(0, 5),
+	(1, 2),
+	(1, 3),
+	(1, 5),
+	(2, 4),
+	(2, 5),
+	(3, 4),
+	(3, 5),
+	(3, 6),
+	(4, 5),
+	(4, 7),
+	(5, 6),
+	(5, 7),
+	(5, 8),
+	(6, 7),
+	(6, 8),
+	(6, 9),
+	(7, 8),
+	(7, 9),
+	(8, 9),
+];
+
+/// Distance between two pillars, in user units
+const UNIT_X: f32 = 70f32;
+/// Distance between two rows, in user units
+const UNIT_Y: f32 = 55f32;
+/// Radius of a Sephira
+const RADIUS: f32 = 20f32;
+
+/// SVG `<a>` element, which sycamore leaves out as it clashes with the HTML one
+struct SvgLink;
+impl sycamore::generic_node::SycamoreElement for SvgLink {
+	const TAG_NAME: &'static str = "a";
+	const NAME_SPACE: Option<&'static str> = Some("http://www.w3.org/2000/svg");
+}
+
+/// Get the center of a Sephira in the drawing
+#[inline]
+fn coords(idx: usize) -> (f32, f32) {
+	let (_, col, row) = SEPHIROT[idx];
+	(col * UNIT_X, row * UNIT_Y)
+}
+
+/// Spirits holding a Sephira of the tree, or its Qlipha
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Node {
+	/// Attribute of the first holder, for its subtitle and assets
+	sephira: Option<Sephira>,
+	sephira_holders: Vec<Rc<Spirit>>,
+	/// Attribute of the first inverse holder, for its subtitle and assets
+	qlipha: Option<Sephira>,
+	qlipha_holders: Vec<Rc<Spirit>>,
+}
+impl Node {
+	/// Get the assets of the node, the Qlipha standing in when no spirit holds the Sephira
+	#[inline]
+	fn attribute(&self) -> Option<&Sephira> {
+		self.sephira.as_ref().or(self.qlipha.as_ref())
+	}
+
+	/// Get one form of each character holding the node, Sephira first
+	fn charas(&self) -> Vec<(&Rc<Spirit>, bool)> {
+		let mut charas: Vec<(&Rc<Spirit>, bool)> = Vec::new();
+		let holders = self.sephira_holders.iter().map(|spirit| (spirit, false));
+		let inverse_holders = self.qlipha_holders.iter().map(|spirit| (spirit, true));
+		for (spirit, inverse) in holders.chain(inverse_holders) {
+			if !charas.iter().any(|(other, other_inverse)| {
+				other.chara_slug() == spirit.chara_slug() && *other_inverse == inverse
+			}) {
+				charas.push((spirit, inverse));
+			}
+		}
+		charas
+	}
+}
+
+/// Sort the shown spirits by the Sephira they hold, in the order of [`SEPHIROT`]
+fn nodes(store: &SpiritStore, spoilers: bool) -> Vec<Node> {
+	let mut nodes = vec![Node::default(); SEPHIROT.len()];
+	for spirit in store
+		.all()
+		.iter()
+		.filter(|spirit| spoilers || !spirit.spoiler())
+	{
+		for sephira in &spirit.sephiras {
+			let idx = sephira
+				.sephira()
+				.and_then(|name| SEPHIROT.iter().position(|(other, ..)| *other == name));
+			let node = match idx {
+				Some(idx) => &mut nodes[idx],
+				None => continue,
+			};
+			let (attribute, holders) = match sephira.wording() {
+				SephiraWording::Qlipha => (&mut node.qlipha, &mut node.qlipha_holders),
+				_ => (&mut node.sephira, &mut node.sephira_holders),
+			};
+			attribute.get_or_insert_with(|| sephira.clone());
+			holders.push(spirit.clone());
+		}
+	}
+	nodes
+}
+
+#[component]
+pub(super) fn Sephirot<'a, G: Html>(cx: Scope<'a>, spoilers_sig: &'a ReadSignal<bool>) -> View<G> {
+	use crate::{
+		router::{self, Route},
+		store,
+	};
+	use sycamore::builder::prelude::*;
+
+	let nodes_sig = store::select(cx, move |store| nodes(store, *spoilers_sig.get()));
+	let selected_sig = create_signal(cx, None::<usize>);
+	let (_, _, last_row) = SEPHIROT[SEPHIROT.len() - 1];
+
+	fragment([
+		h(header)
+			.c(h(h1).t("Tree of Life"))
+			.c(h(nav).c(h(a)
+				.attr("href", router::href(&Route::Home.path()))
+				.t("Back to the list")))
+			.view(cx),
+		h(main)
+			.c(h(svg)
+				.class("tree")
+				.attr("role", "group")
+				.attr("aria-label", "Sephirot")
+				.attr(
+					"viewBox",
+					format!(
+						"{x} {y} {width} {height}",
+						x = -UNIT_X - 2f32 * RADIUS,
+						y = -2f32 * RADIUS,
+						width = 2f32 * (UNIT_X + 2f32 * RADIUS),
+						height = last_row * UNIT_Y + 5f32 * RADIUS,
+					),
+				)
+				.c(View::new_fragment(
+					PATHS
+						.into_iter()
+						.map(|(from, to)| {
+							let (x1, y1) = coords(from);
+							let (x2, y2) = coords(to);
+
+							h(line)
+								.class("path")
+								.attr("x1", x1.to_string())
+								.attr("y1", y1.to_string())
+								.attr("x2", x2.to_string())
+								.attr("y2", y2.to_string())
+								.view(cx)
+						})
+						.collect(),
+				))
+				.c(View::new_dyn_scoped(cx, move |cx| {
+					View::new_fragment(
+						nodes_sig
+							.get()
+							.iter()
+							.cloned()
+							.enumerate()
+							.map(|(idx, node)| {
+								SephiraNode(
+									cx,
+									SephiraNodeProps {
+										idx,
+										node,
+										selected_sig,
+									},
+								)
+							})
+							.collect(),
+					)
+				})))
+			.c(h(aside)
+				.attr("aria-live", "polite")
+				.c(View::new_dyn_scoped(cx, move |cx| match *selected_sig.get() {
+					Some(idx) => SephiraDetails(
+						cx,
+						SephiraDetailsProps {
+							idx,
+							node: nodes_sig.get()[idx].clone(),
+						},
+					),
+					None => h(p)
+						.t("Select a Sephira to see the spirits that hold it.")
+						.view(cx),
+				})))
+			.view(cx),
+	])
+}
+
+#[derive(Prop)]
+struct SephiraNodeProps<'a> {
+	idx: usize,
+	node: Node,
+	selected_sig: &'a Signal<Option<usize>>,
+}
+/// Sephira of the tree, marked with a dot per character holding it
+///
+/// Dots of the characters holding the Qlipha are hollow, and link to their spirit.
+#[component]
+fn SephiraNode<'a, G: Html>(cx: Scope<'a>, props: SephiraNodeProps<'a>) -> View<G> {
+	use crate::router::{self, Route};
+	use sycamore::builder::prelude::*;
+
+	const DOT_RADIUS: f32 = 4f32;
+	const DOT_GAP: f32 = 2f32;
+	let SephiraNodeProps {
+		idx,
+		node,
+		selected_sig,
+	} = props;
+	let (name, _, _) = SEPHIROT[idx];
+	let (x, y) = coords(idx);
+	let icon = create_ref(cx, node.attribute().and_then(Sephira::icon));
+	let charas = node.charas();
+	let holders = charas
+		.iter()
+		.map(|(spirit, _)| spirit.firstname.as_str())
+		.collect::<Vec<_>>()
+		.join(", ");
+	let tooltip = create_ref(
+		cx,
+		if holders.is_empty() {
+			name.to_string()
+		} else {
+			format!("{name}: {holders}")
+		},
+	);
+	let held = !charas.is_empty();
+	let dots_width = charas.len() as f32 * (2f32 * DOT_RADIUS + DOT_GAP) - DOT_GAP;
+
+	h(g).class(if held { "sephira held" } else { "sephira" })
+		.dyn_class("selected", move || *selected_sig.get() == Some(idx))
+		.attr("tabindex", "0")
+		.attr("role", "button")
+		.attr("aria-label", tooltip.as_str())
+		.on("click", move |_| selected_sig.set(Some(idx)))
+		.on("focus", move |_| selected_sig.set(Some(idx)))
+		.c(h(title).t(tooltip))
+		.c(h(circle)
+			.attr("cx", x.to_string())
+			.attr("cy", y.to_string())
+			.attr("r", RADIUS.to_string()))
+		.dyn_if(
+			|| icon.is_some(),
+			move || {
+				h(image)
+					.attr("href", icon.as_ref().unwrap_throw())
+					.attr("x", (x - RADIUS / 2f32).to_string())
+					.attr("y", (y - RADIUS / 2f32).to_string())
+					.attr("width", RADIUS.to_string())
+					.attr("height", RADIUS.to_string())
+			},
+			View::empty,
+		)
+		.c(h(text)
+			.class("name")
+			.attr("x", x.to_string())
+			.attr("y", (y + RADIUS + 8f32).to_string())
+			.t(name))
+		.c(View::new_fragment(
+			charas
+				.into_iter()
+				.enumerate()
+				.map(|(dot_idx, (spirit, inverse))| {
+					let dot_x = x - dots_width / 2f32
+						+ DOT_RADIUS + dot_idx as f32 * (2f32 * DOT_RADIUS + DOT_GAP);
+					let class = create_ref(
+						cx,
+						format!(
+							"holder {chara}{inverse}",
+							chara = spirit.chara_slug(),
+							inverse = if inverse { " inverse" } else { "" }
+						),
+					);
+					let a11y = create_ref(cx, spirit.a11y());
+
+					h(SvgLink)
+						.attr("href", router::href(&Route::spirit(spirit).path()))
+						.attr("aria-label", a11y.as_str())
+						.c(h(title).t(a11y))
+						.c(h(circle)
+							.class(class.as_str())
+							.attr("cx", dot_x.to_string())
+							.attr("cy", (y + RADIUS + 16f32).to_string())
+							.attr("r", DOT_RADIUS.to_string()))
+						.view(cx)
+				})
+				.collect(),
+		))
+		.view(cx)
+}
+
+#[derive(Prop)]
+struct SephiraDetailsProps {
+	idx: usize,
+	node: Node,
+}
+/// Holders of a Sephira and of its Qlipha, with their guardians
+#[component]
+fn SephiraDetails<G: Html>(cx: Scope, props: SephiraDetailsProps) -> View<G> {
+	use super::spirit_link::{SpiritLink, SpiritLinkProps};
+	use sycamore::builder::prelude::*;
+
+	let SephiraDetailsProps { idx, node } = props;
+	let (name, _, _) = SEPHIROT[idx];
+	let node = create_ref(cx, node);
+	let part = move |attribute: Option<&Sephira>, holders: &[Rc<Spirit>]| {
+		let attribute = match attribute {
+			Some(attribute) => attribute,
+			None => return View::empty(),
+		};
+		let heading = create_ref(
+			cx,
+			match attribute.subtitle() {
+				Some(subtitle) => format!(
+					"{wording} · {name} — {subtitle}",
+					wording = attribute.wording(),
+					name = attribute.name()
+				),
+				None => format!(
+					"{wording} · {name}",
+					wording = attribute.wording(),
+					name = attribute.name()
+				),
+			},
+		);
+		let guardian = create_ref(cx, attribute.guardian());
+		let holders = holders.to_vec();
+
+		fragment([
+			h(h3).t(heading).view(cx),
+			h(div)
+				.class("guardian")
+				.dyn_if(
+					|| guardian.is_some(),
+					move || {
+						h(img)
+							.attr("src", guardian.as_ref().unwrap_throw())
+							.attr("alt", "Guardian")
+					},
+					View::empty,
+				)
+				.view(cx),
+			h(ul)
+				.c(View::new_fragment(
+					holders
+						.into_iter()
+						.map(|spirit| {
+							h(li)
+								.c(SpiritLink(
+									cx,
+									SpiritLinkProps {
+										spirit,
+										short: false,
+									},
+								))
+								.view(cx)
+						})
+						.collect(),
+				))
+				.view(cx),
+		])
+	};
+
+	let icon = create_ref(cx, node.attribute().and_then(Sephira::icon));
+
+	fragment([
+		h(h2)
+			.dyn_if(
+				|| icon.is_some(),
+				move || {
+					h(img)
+						.attr("src", icon.as_ref().unwrap_throw())
+						.attr("alt", "")
+				},
+				View::empty,
+			)
+			.t(name)
+			.view(cx),
+		part(node.sephira.as_ref(), &node.sephira_holders),
+		part(node.qlipha.as_ref(), &node.qlipha_holders),
+		if node.attribute().is_none() {
+			h(p).t("No spirit holds this Sephira.").view(cx)
+		} else {
+			View::empty()
+		},
+	])
+}
@@ -0,0 +1,147 @@
+use crate::router::HomeParams;
+use sycamore::prelude::*;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::{Event, HtmlInputElement, HtmlSelectElement};
+
+/// Apply new parameters in a new history entry
+fn set_params(params: &HomeParams) {
+	use crate::router::{self, Route};
+
+	router::navigate_push(&Route::Home.with_query(params));
+}
+
+#[component]
+pub(super) fn Sorting<'a, G: Html>(
+	cx: Scope<'a>,
+	params_sig: &'a ReadSignal<HomeParams>,
+) -> View<G> {
+	use crate::models::{
+		group::GroupBy,
+		sort::{Sort, SortKey},
+		GachaStat, LoreStat,
+	};
+	use sycamore::builder::prelude::*;
+
+	let key_option = |key: SortKey| {
+		let value = create_ref(cx, key.to_string());
+		h(option).attr("value", value.as_str()).t(key.name()).view(cx)
+	};
+
+	h(div)
+		.id("sorting")
+		.c(h(label)
+			.t("Group by ")
+			.c(h(select)
+				.c(View::new_fragment(
+					GroupBy::variants()
+						.into_iter()
+						.map(|group_by| {
+							let value = create_ref(cx, group_by.to_string());
+							h(option)
+								.attr("value", value.as_str())
+								.t(group_by.name())
+								.view(cx)
+						})
+						.collect(),
+				))
+				.dyn_bool_attr("disabled", || params_sig.get().flat)
+				.dyn_prop("value", || params_sig.get().group.to_string())
+				.on("change", |ev: Event| {
+					let group = ev
+						.target()
+						.unwrap_throw()
+						.unchecked_into::<HtmlSelectElement>()
+						.value();
+					set_params(&HomeParams {
+						group: group.parse().unwrap_or_default(),
+						..params_sig.get().as_ref().clone()
+					});
+				})))
+		.c(h(label)
+			.t("Sort by ")
+			.c(h(select)
+				.c(h(option).attr("value", "").t("Dataset order"))
+				.c(key_option(SortKey::Name))
+				.c(key_option(SortKey::Birthdate))
+				.c(key_option(SortKey::Height))
+				.c(key_option(SortKey::Class))
+				.c(key_option(SortKey::Rank))
+				.c(h(optgroup).attr("label", "Lore stats").c(View::new_fragment(
+					LoreStat::variants()
+						.into_iter()
+						.map(|stat| key_option(SortKey::Lore(stat)))
+						.chain([key_option(SortKey::LoreTotal)])
+						.collect(),
+				)))
+				.c(h(optgroup).attr("label", "Gacha stats").c(View::new_fragment(
+					GachaStat::variants()
+						.into_iter()
+						.map(|stat| key_option(SortKey::Gacha(stat)))
+						.chain([key_option(SortKey::GachaTotal)])
+						.collect(),
+				)))
+				.dyn_prop("value", || {
+					params_sig
+						.get()
+						.sort
+						.map(|sort| sort.key.to_string())
+						.unwrap_or_default()
+				})
+				.on("change", |ev: Event| {
+					let key = ev
+						.target()
+						.unwrap_throw()
+						.unchecked_into::<HtmlSelectElement>()
+						.value();
+					set_params(&HomeParams {
+						sort: key.parse().ok().map(Sort::new),
+						..params_sig.get().as_ref().clone()
+					});
+				})))
+		.c(h(button)
+			.attr("type", "button")
+			.dyn_bool_attr("disabled", || params_sig.get().sort.is_none())
+			.dyn_attr("aria-label", || {
+				params_sig.get().sort.map(|sort| {
+					if sort.descending {
+						"Descending order"
+					} else {
+						"Ascending order"
+					}
+				})
+			})
+			.dyn_t(|| match params_sig.get().sort {
+				Some(Sort {
+					descending: true, ..
+				}) => "↓",
+				_ => "↑",
+			})
+			.on("click", |_| {
+				let params = params_sig.get();
+				if let Some(sort) = params.sort {
+					set_params(&HomeParams {
+						sort: Some(Sort {
+							descending: !sort.descending,
+							..sort
+						}),
+						..params.as_ref().clone()
+					});
+				}
+			}))
+		.c(h(label)
+			.c(h(input)
+				.attr("type", "checkbox")
+				.dyn_prop("checked", || params_sig.get().flat)
+				.on("change", |ev: Event| {
+					set_params(&HomeParams {
+						flat: ev
+							.target()
+							.unwrap_throw()
+							.unchecked_into::<HtmlInputElement>()
+							.checked(),
+						..params_sig.get().as_ref().clone()
+					});
+				}))
+			.t("Single list"))
+		.view(cx)
+}
@@ -1,5 +1,8 @@
-use crate::models::{Attribute, Spirit};
-use std::{fmt::Display, marker::PhantomData, rc::Rc};
+use super::stat_graph::{
+	format_value, gacha_axes, lore_axes, StatGraph, StatGraphProps, StatSeries,
+};
+use crate::models::{Attribute, GachaStat, LoreStat, Spirit};
+use std::{cmp::Ordering, marker::PhantomData, rc::Rc};
 use sycamore::{builder::ElementBuilderOrView, prelude::*};
 use wasm_bindgen::UnwrapThrowExt;
 
@@ -333,114 +336,141 @@ fn PersonalInfoSection<G: Html>(cx: Scope, spirit: Rc<Spirit>) -> View<G> {
 		.view(cx)
 }
 
+/// Stat of a spirit, put in the context of the whole dataset
+#[derive(Debug, Clone, PartialEq)]
+struct StatInsight {
+	name: &'static str,
+	value: f32,
+	/// Percentile rank among the spirits that have the stat
+	percentile: f32,
+	/// Average among the spirits that have the stat
+	average: f32,
+}
+impl StatInsight {
+	fn new<F: Fn(&Spirit) -> Option<f32>>(
+		name: &'static str,
+		spirit: &Spirit,
+		spirits: &[Rc<Spirit>],
+		get: F,
+	) -> Option<Self> {
+		use crate::models::stats::Distribution;
+
+		let value = get(spirit)?;
+		let distribution = Distribution::of(spirits, &get);
+		Some(Self {
+			name,
+			value,
+			percentile: distribution.percentile_rank(value)?,
+			average: distribution.mean()?,
+		})
+	}
+
+	/// Compare the value of the spirit with the average
+	#[inline]
+	fn relation(&self) -> Ordering {
+		self.value
+			.partial_cmp(&self.average)
+			.unwrap_or(Ordering::Equal)
+	}
+
+	/// Get the reference polygon of the averages of some stats
+	fn average_series(insights: &[Self]) -> StatSeries {
+		StatSeries {
+			label: "Dataset average".to_string(),
+			class: "average".to_string(),
+			values: insights.iter().map(|insight| insight.average).collect(),
+		}
+	}
+
+	/// Sum up how some stats compare with the averages, total first
+	fn summary(insights: &[Self], total: Option<&Self>) -> String {
+		let names = |relation: Ordering| {
+			insights
+				.iter()
+				.filter(|insight| insight.relation() == relation)
+				.map(|insight| insight.name)
+				.collect::<Vec<_>>()
+				.join(", ")
+		};
+		let mut sentences = Vec::new();
+		if let Some(total) = total {
+			let relation = match total.relation() {
+				Ordering::Greater => "above",
+				Ordering::Less => "below",
+				Ordering::Equal => "on",
+			};
+			sentences.push(format!(
+				"Total of {value}, {relation} the dataset average of {average}.",
+				value = format_value(total.value),
+				average = format_value(total.average),
+			));
+		}
+		for (relation, wording) in [(Ordering::Greater, "Above"), (Ordering::Less, "Below")] {
+			let listed = names(relation);
+			if !listed.is_empty() {
+				sentences.push(format!("{wording} average in {listed}."));
+			}
+		}
+		sentences.join(" ")
+	}
+}
+
 #[derive(Prop)]
-struct StatGraphProps<'r, N> {
-	stats: &'r [(&'static str, &'static str, N)],
-	max_value: N,
+struct StatInsightsProps<'a> {
+	insights: &'a [StatInsight],
+	total: &'a Option<StatInsight>,
 }
+/// Table of stats with their percentile ranks and the averages of the dataset
 #[component]
-fn StatGraph<N: Into<f32> + Copy + Display, G: Html>(
-	cx: Scope,
-	props: StatGraphProps<N>,
-) -> View<G> {
-	use std::f32::consts::PI;
+fn StatInsights<'a, G: Html>(cx: Scope<'a>, props: StatInsightsProps<'a>) -> View<G> {
+	use crate::models::ordinal_number;
 	use sycamore::builder::prelude::*;
 
-	const SCALE: f32 = 100f32;
-	const MARGIN: f32 = 50f32;
-	const CLASS_WIREFRAME: &str = "wireframe";
-	let StatGraphProps { stats, max_value } = props;
-	let max_value = max_value.into();
-	let len = stats.len();
-	let get_coords = |point_idx: f32| {
-		let (mut y, mut x) = ((1f32 / 2f32 + 2f32 * point_idx / len as f32) * PI).sin_cos();
-		x *= SCALE;
-		y *= -SCALE;
-		(x, y)
+	let StatInsightsProps { insights, total } = props;
+	let overview = create_ref(cx, StatInsight::summary(insights, total.as_ref()));
+	let row = move |insight: &'a StatInsight| {
+		let value = create_ref(cx, format_value(insight.value));
+		let percentile = create_ref(cx, ordinal_number(insight.percentile.round() as usize));
+		let average = create_ref(cx, format_value(insight.average));
+
+		h(tr)
+			.dyn_class("above", move || insight.relation() == Ordering::Greater)
+			.dyn_class("below", move || insight.relation() == Ordering::Less)
+			.c(h(th).attr("scope", "row").t(insight.name))
+			.c(h(td).t(value))
+			.c(h(td).t(percentile))
+			.c(h(td).t(average))
+			.view(cx)
 	};
-	let acc_points = |acc: String, (x, y): (f32, f32)| acc + &format!(" {x},{y}");
-
-	h(svg)
-		.attr(
-			"viewBox",
-			format!(
-				"-{min} -{min} {size} {size}",
-				min = SCALE + MARGIN,
-				size = 2f32 * (SCALE + MARGIN)
-			),
-		)
-		.c(h(polygon).class(CLASS_WIREFRAME).attr(
-			"points",
-			(0..len)
-				.map(|idx| get_coords(idx as f32))
-				.fold(String::new(), acc_points),
-		))
-		.c(View::new_fragment(
-			(0..len)
-				.map(|idx| {
-					let (x, y) = get_coords(idx as f32);
-
-					h(line)
-						.class(CLASS_WIREFRAME)
-						.attr("x1", "0")
-						.attr("y1", "0")
-						.attr("x2", x.to_string())
-						.attr("y2", y.to_string())
-						.view(cx)
-				})
-				.collect(),
-		))
-		.c(h(polygon).attr(
-			"points",
-			stats
-				.iter()
-				.enumerate()
-				.map(|(idx, (_, _, val))| {
-					let (mut x, mut y) = get_coords(idx as f32);
-					let val = (*val).into();
-					if val <= max_value {
-						let ratio = val / max_value;
-						x *= ratio;
-						y *= ratio;
-					}
-					(x, y)
-				})
-				.fold(String::new(), acc_points),
-		))
-		.c(View::new_fragment(
-			stats
-				.iter()
-				.enumerate()
-				.map(|(idx, (name, abbrev, val))| {
-					const RATIO: f32 = 1.05;
-					const X_LIMIT: f32 = 90f32;
-					const Y_LIMIT: f32 = 50f32;
-
-					let (mut x, mut y) = get_coords(idx as f32);
-					x *= RATIO;
-					y *= RATIO;
-					let name = create_ref(cx, name.to_string());
-					let abbrev = create_ref(cx, format!("{abbrev} "));
-					let val = create_ref(cx, val.to_string());
-
-					h(text)
-						.attr("x", x.to_string())
-						.attr("y", y.to_string())
-						.dyn_class("anchor-x-middle", move || x.abs() < X_LIMIT)
-						.dyn_class("anchor-x-end", move || x <= -X_LIMIT)
-						.dyn_class("anchor-y-top", move || y >= Y_LIMIT)
-						.c(h(title).t(name))
-						.c(h(tspan).class(super::CLASS_TEXTBF).t(abbrev))
-						.t(val)
-						.view(cx)
-				})
-				.collect(),
-		))
-		.view(cx)
+
+	fragment([
+		h(table)
+			.class("insights")
+			.c(h(thead).c(h(tr)
+				.c(h(th).attr("scope", "col").t("Stat"))
+				.c(h(th).attr("scope", "col").t("Value"))
+				.c(h(th)
+					.attr("scope", "col")
+					.attr(
+						"title",
+						"Percentile rank among the spirits that have the stat",
+					)
+					.t("Percentile"))
+				.c(h(th).attr("scope", "col").t("Average"))))
+			.c(h(tbody).c(View::new_fragment(insights.iter().map(row).collect())))
+			.dyn_if(
+				|| total.is_some(),
+				move || h(tfoot).c(row(total.as_ref().unwrap_throw())),
+				View::empty,
+			)
+			.view(cx),
+		h(p).class("summary").t(overview).view(cx),
+	])
 }
 
 #[component]
 fn LoreStatsSection<'a, G: Html>(cx: Scope<'a>, spirit: Rc<Spirit>) -> View<G> {
+	use crate::store;
 	use sycamore::builder::prelude::*;
 
 	const SEC_ID: &str = "lore-stats-hd";
@@ -448,26 +478,27 @@ fn LoreStatsSection<'a, G: Html>(cx: Scope<'a>, spirit: Rc<Spirit>) -> View<G> {
 	let spacequake_class = create_ref(cx, spirit.spacequake_class.map(|class| class.icon()));
 	let angel_class = create_ref(cx, spirit.angel_class.map(|class| class.icon()));
 	let astraldress_class = create_ref(cx, spirit.astraldress_class.map(|class| class.icon()));
-	let stats = create_ref(
+	let store = store::use_store(cx).get();
+	let insights = create_ref(
 		cx,
-		spirit
-			.strength
-			.zip(spirit.consistency)
-			.zip(spirit.spirit_power)
-			.zip(spirit.agility)
-			.zip(spirit.intelligence)
-			.map(
-				|((((strength, consistency), spirit_power), agility), intelligence)| {
-					[
-						("Strength", "STR", strength),
-						("Consistency", "CST", consistency),
-						("Spiritual Power", "SPI", spirit_power),
-						("Agility", "AGI", agility),
-						("Intelligence", "INT", intelligence),
-					]
-				},
-			),
+		LoreStat::variants()
+			.into_iter()
+			.map(|stat| {
+				StatInsight::new(stat.name(), &spirit, store.all(), |spirit| {
+					stat.of(spirit).map(f32::from)
+				})
+			})
+			.collect::<Option<Vec<_>>>(),
 	);
+	let total = create_ref(
+		cx,
+		StatInsight::new("Total", &spirit, store.all(), |spirit| {
+			spirit.lore_total().map(f32::from)
+		}),
+	);
+	let stats = create_ref(cx, StatSeries::lore(&spirit, spirit.a11y(), String::new()));
+	let average = create_ref(cx, insights.as_deref().map(StatInsight::average_series));
+	let axes = create_ref(cx, lore_axes());
 
 	let el_builder = move |(src, rep): &'a (String, u8)| {
 		View::new_fragment(
@@ -504,18 +535,34 @@ fn LoreStatsSection<'a, G: Html>(cx: Scope<'a>, spirit: Rc<Spirit>) -> View<G> {
 					StatGraph(
 						cx,
 						StatGraphProps {
-							stats: &stats.unwrap_throw(),
-							max_value: 300,
+							axes,
+							series: std::slice::from_ref(stats.as_ref().unwrap_throw()),
+							reference: average.as_ref(),
+							max_value: LoreStat::MAX.into(),
 						},
 					)
 				},
 				View::empty,
 			))
+		.dyn_if(
+			|| insights.is_some(),
+			move || {
+				StatInsights(
+					cx,
+					StatInsightsProps {
+						insights: insights.as_ref().unwrap_throw(),
+						total,
+					},
+				)
+			},
+			View::empty,
+		)
 		.view(cx)
 }
 
 #[component]
 fn GachaStatsSection<G: Html>(cx: Scope, spirit: Rc<Spirit>) -> View<G> {
+	use crate::store;
 	use sycamore::builder::prelude::*;
 
 	const SEC_ID: &str = "gacha-stats-hd";
@@ -523,28 +570,27 @@ fn GachaStatsSection<G: Html>(cx: Scope, spirit: Rc<Spirit>) -> View<G> {
 		cx,
 		spirit.rank.map(|class| (class.to_string(), class.icon())),
 	);
-	let stats = create_ref(
+	let store = store::use_store(cx).get();
+	let insights = create_ref(
 		cx,
-		spirit
-			.attack
-			.zip(spirit.combo)
-			.zip(spirit.support)
-			.zip(spirit.defense)
-			.zip(spirit.control)
-			.zip(spirit.damage)
-			.map(
-				|(((((attack, combo), support), defense), control), damage)| {
-					[
-						("Attack", "ATK", attack),
-						("Combination", "CMB", combo),
-						("Support", "SUP", support),
-						("Defense", "DEF", defense),
-						("Control", "CTL", control),
-						("Damage", "DMG", damage),
-					]
-				},
-			),
+		GachaStat::variants()
+			.into_iter()
+			.map(|stat| {
+				StatInsight::new(stat.name(), &spirit, store.all(), |spirit| {
+					stat.of(spirit).map(f32::from)
+				})
+			})
+			.collect::<Option<Vec<_>>>(),
 	);
+	let total = create_ref(
+		cx,
+		StatInsight::new("Total", &spirit, store.all(), |spirit| {
+			spirit.gacha_total().map(f32::from)
+		}),
+	);
+	let stats = create_ref(cx, StatSeries::gacha(&spirit, spirit.a11y(), String::new()));
+	let average = create_ref(cx, insights.as_deref().map(StatInsight::average_series));
+	let axes = create_ref(cx, gacha_axes());
 
 	h(section)
 		.id("gacha-stats")
@@ -594,12 +640,27 @@ fn GachaStatsSection<G: Html>(cx: Scope, spirit: Rc<Spirit>) -> View<G> {
 					StatGraph(
 						cx,
 						StatGraphProps {
-							stats: &stats.unwrap_throw(),
-							max_value: 100,
+							axes,
+							series: std::slice::from_ref(stats.as_ref().unwrap_throw()),
+							reference: average.as_ref(),
+							max_value: GachaStat::MAX.into(),
 						},
 					)
 				},
 				View::empty,
 			))
+		.dyn_if(
+			|| insights.is_some(),
+			move || {
+				StatInsights(
+					cx,
+					StatInsightsProps {
+						insights: insights.as_ref().unwrap_throw(),
+						total,
+					},
+				)
+			},
+			View::empty,
+		)
 		.view(cx)
 }
@@ -0,0 +1,46 @@
+use crate::models::Spirit;
+use std::rc::Rc;
+use sycamore::prelude::*;
+use wasm_bindgen::UnwrapThrowExt;
+
+#[derive(Prop)]
+pub(super) struct SpiritLinkProps {
+	pub spirit: Rc<Spirit>,
+	/// Only write the first name, the full one being given to assistive technologies
+	pub short: bool,
+}
+/// Link to the details of a spirit, with its face
+#[component]
+pub(super) fn SpiritLink<G: Html>(cx: Scope, props: SpiritLinkProps) -> View<G> {
+	use crate::router::{self, Route};
+	use sycamore::builder::prelude::*;
+
+	let SpiritLinkProps { spirit, short } = props;
+	let href = create_ref(cx, router::href(&Route::spirit(&spirit).path()));
+	let class = create_ref(cx, format!("chara {}", spirit.chara_slug()));
+	let a11y = create_ref(cx, spirit.a11y());
+	let name = create_ref(
+		cx,
+		if short {
+			spirit.firstname.clone()
+		} else {
+			a11y.clone()
+		},
+	);
+	let icon = create_ref(cx, spirit.icon());
+
+	h(a).attr("href", href)
+		.class(class)
+		.attr("aria-label", a11y)
+		.dyn_if(
+			|| icon.is_some(),
+			move || {
+				h(img)
+					.attr("src", icon.as_ref().unwrap_throw())
+					.attr("alt", "")
+			},
+			View::empty,
+		)
+		.t(name)
+		.view(cx)
+}
@@ -0,0 +1,335 @@
+use crate::models::{GachaStat, LoreStat, Spirit};
+use sycamore::prelude::*;
+
+/// Number of concentric rings drawn in a [`StatGraph`], the outer one included
+const RINGS: usize = 4;
+
+/// Data series drawn by a [`StatGraph`]
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct StatSeries {
+	pub label: String,
+	/// Classes of the polygon, which set its colors
+	pub class: String,
+	pub values: Vec<f32>,
+}
+impl StatSeries {
+	/// Build the series of the lore stats of a spirit, if they are all known
+	pub fn lore(spirit: &Spirit, label: String, class: String) -> Option<Self> {
+		Some(Self {
+			label,
+			class,
+			values: LoreStat::variants()
+				.into_iter()
+				.map(|stat| stat.of(spirit).map(f32::from))
+				.collect::<Option<_>>()?,
+		})
+	}
+
+	/// Build the series of the gacha stats of a spirit, if they are all known
+	pub fn gacha(spirit: &Spirit, label: String, class: String) -> Option<Self> {
+		Some(Self {
+			label,
+			class,
+			values: GachaStat::variants()
+				.into_iter()
+				.map(|stat| stat.of(spirit).map(f32::from))
+				.collect::<Option<_>>()?,
+		})
+	}
+}
+
+/// Get the axes of a graph of lore stats
+#[inline]
+pub(super) fn lore_axes() -> Vec<(&'static str, &'static str)> {
+	LoreStat::variants()
+		.into_iter()
+		.map(|stat| (stat.name(), stat.abbrev()))
+		.collect()
+}
+
+/// Get the axes of a graph of gacha stats
+#[inline]
+pub(super) fn gacha_axes() -> Vec<(&'static str, &'static str)> {
+	GachaStat::variants()
+		.into_iter()
+		.map(|stat| (stat.name(), stat.abbrev()))
+		.collect()
+}
+
+/// Format a value without trailing zeros, rounded to a decimal
+pub(super) fn format_value(value: f32) -> String {
+	if value.fract() == 0f32 {
+		value.to_string()
+	} else {
+		format!("{value:.1}")
+	}
+}
+
+#[derive(Prop)]
+pub(super) struct StatGraphProps<'a> {
+	/// Name and abbreviation of each axis
+	pub axes: &'a [(&'static str, &'static str)],
+	pub series: &'a [StatSeries],
+	/// Series drawn as a plain outline over the others, such as an average
+	pub reference: Option<&'a StatSeries>,
+	/// Value of the outer ring, larger values being drawn on it
+	pub max_value: f32,
+}
+/// Radar chart of stats, overlaying several series
+///
+/// Values are only written next to the axes when there is a single series,
+/// otherwise a legend is shown under the chart.
+/// The reference series has neither values nor a legend.
+/// Hovering or focusing a point shows its exact value.
+#[component]
+pub(super) fn StatGraph<'a, G: Html>(cx: Scope<'a>, props: StatGraphProps<'a>) -> View<G> {
+	use std::f32::consts::PI;
+	use sycamore::builder::prelude::*;
+
+	const SCALE: f32 = 100f32;
+	const MARGIN: f32 = 50f32;
+	const CLASS_WIREFRAME: &str = "wireframe";
+	let StatGraphProps {
+		axes,
+		series,
+		reference,
+		max_value,
+	} = props;
+	let len = axes.len();
+	let get_coords = move |point_idx: f32| {
+		let (mut y, mut x) = ((1f32 / 2f32 + 2f32 * point_idx / len as f32) * PI).sin_cos();
+		x *= SCALE;
+		y *= -SCALE;
+		(x, y)
+	};
+	let get_value_coords = move |point_idx: usize, val: f32| {
+		let (x, y) = get_coords(point_idx as f32);
+		let ratio = val.clamp(0f32, max_value) / max_value;
+		(x * ratio, y * ratio)
+	};
+	let acc_points = |acc: String, (x, y): (f32, f32)| acc + &format!(" {x},{y}");
+	// Position and text of the tooltip of the hovered or focused point
+	let tooltip_sig = create_signal(cx, None::<(f32, f32, &'a str)>);
+
+	let graph = h(svg)
+		.dyn_class("overlaid", move || series.len() > 1)
+		.attr(
+			"viewBox",
+			format!(
+				"-{min} -{min} {size} {size}",
+				min = SCALE + MARGIN,
+				size = 2f32 * (SCALE + MARGIN)
+			),
+		)
+		.c(View::new_fragment(
+			(1..=RINGS)
+				.map(|ring| {
+					let ratio = ring as f32 / RINGS as f32;
+					let ring_value = create_ref(cx, format_value(max_value * ratio));
+
+					fragment([
+						h(polygon)
+							.class(CLASS_WIREFRAME)
+							.dyn_class("ring", move || ring < RINGS)
+							.attr(
+								"points",
+								(0..len)
+									.map(|idx| {
+										let (x, y) = get_coords(idx as f32);
+										(x * ratio, y * ratio)
+									})
+									.fold(String::new(), acc_points),
+							)
+							.view(cx),
+						h(text)
+							.class("ring-value")
+							.attr("x", "2")
+							.attr("y", (-SCALE * ratio).to_string())
+							.t(ring_value)
+							.view(cx),
+					])
+				})
+				.collect(),
+		))
+		.c(View::new_fragment(
+			(0..len)
+				.map(|idx| {
+					let (x, y) = get_coords(idx as f32);
+
+					h(line)
+						.class(CLASS_WIREFRAME)
+						.attr("x1", "0")
+						.attr("y1", "0")
+						.attr("x2", x.to_string())
+						.attr("y2", y.to_string())
+						.view(cx)
+				})
+				.collect(),
+		))
+		.c(View::new_fragment(
+			series
+				.iter()
+				.enumerate()
+				.map(
+					|(
+						series_idx,
+						StatSeries {
+							label: series_name,
+							class,
+							values,
+						},
+					)| {
+						h(polygon)
+							.class(format!("series series-{series_idx} {class}"))
+							.attr(
+								"points",
+								values
+									.iter()
+									.enumerate()
+									.map(|(idx, val)| get_value_coords(idx, *val))
+									.fold(String::new(), acc_points),
+							)
+							.c(h(title).t(series_name))
+							.view(cx)
+					},
+				)
+				.collect(),
+		))
+		.c(match reference {
+			Some(StatSeries {
+				label: series_name,
+				class,
+				values,
+			}) => h(polygon)
+				.class(format!("reference {class}"))
+				.attr(
+					"points",
+					values
+						.iter()
+						.enumerate()
+						.map(|(idx, val)| get_value_coords(idx, *val))
+						.fold(String::new(), acc_points),
+				)
+				.c(h(title).t(series_name))
+				.view(cx),
+			None => View::empty(),
+		})
+		.c(View::new_fragment(
+			axes.iter()
+				.enumerate()
+				.map(|(idx, (name, abbrev))| {
+					const RATIO: f32 = 1.05;
+					const X_LIMIT: f32 = 90f32;
+					const Y_LIMIT: f32 = 50f32;
+
+					let (mut x, mut y) = get_coords(idx as f32);
+					x *= RATIO;
+					y *= RATIO;
+					let abbrev = create_ref(cx, format!("{abbrev} "));
+					let val = create_ref(
+						cx,
+						match series {
+							[single] => format_value(single.values[idx]),
+							_ => String::new(),
+						},
+					);
+
+					h(text)
+						.attr("x", x.to_string())
+						.attr("y", y.to_string())
+						.dyn_class("anchor-x-middle", move || x.abs() < X_LIMIT)
+						.dyn_class("anchor-x-end", move || x <= -X_LIMIT)
+						.dyn_class("anchor-y-top", move || y >= Y_LIMIT)
+						.c(h(title).t(name))
+						.c(h(tspan).class(super::CLASS_TEXTBF).t(abbrev))
+						.t(val)
+						.view(cx)
+				})
+				.collect(),
+		))
+		.c(View::new_fragment(
+			series
+				.iter()
+				.enumerate()
+				.flat_map(
+					|(
+						series_idx,
+						StatSeries {
+							label: series_name,
+							values,
+							..
+						},
+					)| {
+						values
+							.iter()
+							.zip(axes)
+							.enumerate()
+							.map(move |(idx, (val, (name, _)))| {
+								let (x, y) = get_value_coords(idx, *val);
+								let tooltip = create_ref(
+									cx,
+									format!("{series_name}: {name} {}", format_value(*val)),
+								);
+								let show = move |_| tooltip_sig.set(Some((x, y, tooltip.as_str())));
+								let hide = move |_| tooltip_sig.set(None);
+
+								h(circle)
+									.class(format!("point series-{series_idx}"))
+									.attr("cx", x.to_string())
+									.attr("cy", y.to_string())
+									.attr("r", "3")
+									.attr("tabindex", "0")
+									.attr("aria-label", tooltip.as_str())
+									.on("mouseenter", show)
+									.on("focus", show)
+									.on("mouseleave", hide)
+									.on("blur", hide)
+									.view(cx)
+							})
+					},
+				)
+				.collect(),
+		))
+		.dyn_c(move || match *tooltip_sig.get() {
+			Some((x, y, tooltip)) => h(text)
+				.class("tooltip")
+				.attr("x", x.to_string())
+				.attr("y", (y - 6f32).to_string())
+				.t(tooltip)
+				.view(cx),
+			None => View::empty(),
+		});
+
+	h(figure)
+		.class("stat-graph")
+		.c(graph)
+		.dyn_if(
+			|| series.len() > 1,
+			move || {
+				h(figcaption).c(h(ul).class("legend").c(View::new_fragment(
+					series
+						.iter()
+						.enumerate()
+						.map(
+							|(
+								series_idx,
+								StatSeries {
+									label: series_name,
+									class,
+									..
+								},
+							)| {
+								h(li)
+									.class(format!("series-{series_idx} {class}"))
+									.c(h(span).class("swatch").attr("aria-hidden", "true"))
+									.t(series_name)
+									.view(cx)
+							},
+						)
+						.collect(),
+				)))
+			},
+			View::empty,
+		)
+		.view(cx)
+}
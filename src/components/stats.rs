@@ -0,0 +1,185 @@
+use super::chart::{Bar, BarChart, BarChartProps, Histogram, HistogramProps};
+use crate::{models::Spirit, store::SpiritStore};
+use std::{fmt::Display, rc::Rc};
+use sycamore::prelude::*;
+
+/// Width of the bins of the height histogram, in centimeters
+const HEIGHT_BIN: f32 = 5f32;
+/// Width of the bins of the weight histogram, in kilograms
+const WEIGHT_BIN: f32 = 5f32;
+
+/// Get the spirits shown
+#[inline]
+fn visible(store: &SpiritStore, spoilers: bool) -> Vec<Rc<Spirit>> {
+	store
+		.all()
+		.iter()
+		.filter(|spirit| spoilers || !spirit.spoiler())
+		.cloned()
+		.collect()
+}
+
+/// Get a value of each character, from its first shown form that has one
+///
+/// Forms of a character usually share their personal info, so they are counted once.
+fn chara_values<T, F>(store: &SpiritStore, spoilers: bool, get: F) -> Vec<T>
+where
+	F: Fn(&Spirit) -> Option<T>,
+{
+	store
+		.charas()
+		.filter_map(|chara| {
+			store
+				.chara(chara)
+				.filter(|spirit| spoilers || !spirit.spoiler())
+				.find_map(|spirit| get(spirit))
+		})
+		.collect()
+}
+
+/// Leave a bar without classes
+#[inline(always)]
+fn no_class<C>(_: &C) -> String {
+	String::new()
+}
+
+/// Count the items in each category, in the order of `categories`
+fn count_bars<C, T, I, F>(categories: I, items: &[T], class: fn(&C) -> String, is_in: F) -> Vec<Bar>
+where
+	C: Display,
+	I: IntoIterator<Item = C>,
+	F: Fn(&T, &C) -> bool,
+{
+	categories
+		.into_iter()
+		.map(|category| Bar {
+			label: category.to_string(),
+			class: class(&category),
+			value: items.iter().filter(|item| is_in(item, &category)).count() as f32,
+		})
+		.collect()
+}
+
+#[component]
+pub(super) fn Stats<'a, G: Html>(cx: Scope<'a>, spoilers_sig: &'a ReadSignal<bool>) -> View<G> {
+	use crate::{
+		models::{
+			stats::{Bin, Distribution},
+			Class, Element, Medium, Month, Series,
+		},
+		router::{self, Route},
+		store,
+	};
+	use sycamore::builder::prelude::*;
+
+	let heights_sig = store::select(cx, move |store| {
+		Distribution::new(chara_values(store, *spoilers_sig.get(), |spirit| {
+			spirit.height.map(f32::from)
+		}))
+		.histogram(HEIGHT_BIN)
+	});
+	let weights_sig = store::select(cx, move |store| {
+		Distribution::new(chara_values(store, *spoilers_sig.get(), |spirit| {
+			spirit.weight.map(f32::from)
+		}))
+		.histogram(WEIGHT_BIN)
+	});
+	let months_sig = store::select(cx, move |store| {
+		let months = chara_values(store, *spoilers_sig.get(), |spirit| {
+			spirit.birthdate.map(|birthdate| birthdate.month())
+		});
+		count_bars(Month::variants(), &months, no_class, |month, other| {
+			month == other
+		})
+	});
+	let elements_sig = store::select(cx, move |store| {
+		count_bars(
+			Element::variants(),
+			&visible(store, *spoilers_sig.get()),
+			|element| format!("element {element}"),
+			|spirit, element| spirit.elements.contains(*element),
+		)
+	});
+	let media_sig = store::select(cx, move |store| {
+		count_bars(
+			Medium::variants(),
+			&visible(store, *spoilers_sig.get()),
+			|medium| format!("{medium:?}"),
+			|spirit, medium| spirit.media.contains(*medium),
+		)
+	});
+	let series_sig = store::select(cx, move |store| {
+		count_bars(
+			Series::variants(),
+			&visible(store, *spoilers_sig.get()),
+			no_class,
+			|spirit, series| spirit.series() == *series,
+		)
+	});
+	let ranks_sig = store::select(cx, move |store| {
+		count_bars(
+			Class::variants(),
+			&visible(store, *spoilers_sig.get()),
+			no_class,
+			|spirit, rank| spirit.rank == Some(*rank),
+		)
+	});
+	let classes_sig = store::select(cx, move |store| {
+		count_bars(
+			Class::variants(),
+			&visible(store, *spoilers_sig.get()),
+			no_class,
+			|spirit, class| spirit.class == Some(*class),
+		)
+	});
+
+	let chart_section = move |id: &'static str, heading: &'static str| {
+		let heading_id = create_ref(cx, format!("{id}-hd"));
+
+		h(section)
+			.id(id)
+			.attr("aria-labelledby", heading_id.as_str())
+			.c(h(h2).attr("id", heading_id.as_str()).t(heading))
+	};
+	let histogram = move |bins_sig: &'a ReadSignal<Vec<Bin>>, unit| {
+		move || {
+			let bins = create_ref(cx, bins_sig.get());
+			Histogram(
+				cx,
+				HistogramProps {
+					bins,
+					unit,
+					counted: "characters",
+				},
+			)
+		}
+	};
+	let bar_chart = move |bars_sig: &'a ReadSignal<Vec<Bar>>, unit| {
+		move || {
+			let bars = create_ref(cx, bars_sig.get());
+			BarChart(cx, BarChartProps { bars, unit })
+		}
+	};
+
+	fragment([
+		h(header)
+			.c(h(h1).t("Statistics"))
+			.c(h(nav).c(h(a)
+				.attr("href", router::href(&Route::Home.path()))
+				.t("Back to the list")))
+			.view(cx),
+		h(main)
+			.c(chart_section("heights", "Height").dyn_c(histogram(heights_sig, "cm")))
+			.c(chart_section("weights", "Weight").dyn_c(histogram(weights_sig, "kg")))
+			.c(chart_section("months", "Birthdays per month")
+				.dyn_c(bar_chart(months_sig, "characters")))
+			.c(chart_section("elements", "Spirits per element")
+				.dyn_c(bar_chart(elements_sig, "spirits")))
+			.c(chart_section("media", "Spirits per medium").dyn_c(bar_chart(media_sig, "spirits")))
+			.c(chart_section("series", "Spirits per series")
+				.dyn_c(bar_chart(series_sig, "spirits")))
+			.c(chart_section("ranks", "Ranks").dyn_c(bar_chart(ranks_sig, "spirits")))
+			.c(chart_section("classes", "Classes").dyn_c(bar_chart(classes_sig, "spirits")))
+			.view(cx),
+	])
+}
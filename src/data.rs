@@ -0,0 +1,330 @@
+use crate::{
+	models::{Error, Format, Spirit},
+	store::SpiritStore,
+};
+use std::{
+	fmt::{self, Display, Formatter},
+	future::Future,
+	pin::Pin,
+	rc::Rc,
+};
+use sycamore::prelude::*;
+use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+use web_sys::Performance;
+
+const PERF_MARK_START: &str = "::start";
+const PERF_MARK_END: &str = "::end";
+const PERF_MEASURE_FETCH: &str = "fetch";
+const PERF_MARK_FETCH_START: &str = constcat!(PERF_MEASURE_FETCH, PERF_MARK_START);
+const PERF_MARK_FETCH_END: &str = constcat!(PERF_MEASURE_FETCH, PERF_MARK_END);
+const PERF_MEASURE_DESER: &str = "deser";
+const PERF_MARK_DESER_START: &str = constcat!(PERF_MEASURE_DESER, PERF_MARK_START);
+const PERF_MARK_DESER_END: &str = constcat!(PERF_MEASURE_DESER, PERF_MARK_END);
+
+/// Name of the URL search parameter overriding the default data source
+const QUERY_DATA: &str = "data";
+
+/// Future of the data loaded by a [`DataSource`]
+pub(crate) type LoadFuture<'s> = Pin<Box<dyn Future<Output = Result<Vec<Spirit>, Error>> + 's>>;
+
+/// Where the character data comes from
+pub(crate) trait DataSource: Display {
+	fn load(&self) -> LoadFuture<'_>;
+}
+
+/// Get a readable message out of a JavaScript exception
+fn js_error_message(err: &JsValue) -> String {
+	err.as_string()
+		.or_else(|| {
+			err.dyn_ref::<js_sys::Error>()
+				.map(|err| err.message().into())
+		})
+		.unwrap_or_else(|| format!("{err:?}"))
+}
+
+#[inline]
+fn performance() -> Performance {
+	web_sys::window()
+		.and_then(|window| window.performance())
+		.unwrap_throw()
+}
+
+fn parse(text: &str, format: Format) -> Result<Vec<Spirit>, Error> {
+	let perf = performance();
+	perf.mark(PERF_MARK_DESER_START).unwrap_throw();
+	let res = match format {
+		Format::Csv => crate::models::from_csv(text),
+		Format::Json => crate::models::from_json(text),
+	};
+	perf.mark(PERF_MARK_DESER_END).unwrap_throw();
+	res
+}
+
+/// Dataset served over HTTP
+///
+/// The format is picked from the `Content-Type` of the response.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpSource {
+	url: String,
+}
+impl HttpSource {
+	#[inline(always)]
+	pub fn new<S: Into<String>>(url: S) -> Self {
+		Self { url: url.into() }
+	}
+}
+impl Display for HttpSource {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.url)
+	}
+}
+impl DataSource for HttpSource {
+	fn load(&self) -> LoadFuture<'_> {
+		Box::pin(async move {
+			use wasm_bindgen_futures::JsFuture;
+			use web_sys::{Request, RequestInit, Response};
+
+			let window = web_sys::window().unwrap_throw();
+			let perf = performance();
+			let network_err = |err: JsValue| Error::Network(js_error_message(&err));
+
+			let mut req = RequestInit::new();
+			req.method("GET");
+			let req = Request::new_with_str_and_init(&self.url, &req).map_err(network_err)?;
+			req.headers()
+				.set(
+					"Accept",
+					&format!(
+						"{csv}, {json};q=0.9",
+						csv = Format::Csv.media_type(),
+						json = Format::Json.media_type()
+					),
+				)
+				.unwrap_throw();
+			perf.mark(PERF_MARK_FETCH_START).unwrap_throw();
+			let res: Response = JsFuture::from(window.fetch_with_request(&req))
+				.await
+				.map_err(network_err)?
+				.unchecked_into();
+			perf.mark(PERF_MARK_FETCH_END).unwrap_throw();
+			if !res.ok() {
+				return Err(Error::HttpStatus {
+					status: res.status(),
+					reason: res.status_text(),
+				});
+			}
+			let format = res
+				.headers()
+				.get("Content-Type")
+				.ok()
+				.flatten()
+				.and_then(|content_type| Format::from_content_type(&content_type))
+				.or_else(|| Format::from_path(&self.url))
+				.unwrap_or(Format::Csv);
+			let text = JsFuture::from(res.text().unwrap_throw())
+				.await
+				.map_err(network_err)?
+				.as_string()
+				.unwrap_throw();
+
+			parse(&text, format)
+		})
+	}
+}
+
+/// Dataset compiled into the application by the build script
+#[cfg(feature = "embedded-data")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EmbeddedSource;
+#[cfg(feature = "embedded-data")]
+impl Display for EmbeddedSource {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "embedded dataset")
+	}
+}
+#[cfg(feature = "embedded-data")]
+impl DataSource for EmbeddedSource {
+	fn load(&self) -> LoadFuture<'_> {
+		Box::pin(async move {
+			static DATA: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/spirits.bin"));
+
+			let perf = performance();
+			perf.mark(PERF_MARK_DESER_START).unwrap_throw();
+			let res = crate::models::binary::from_bytes(DATA)
+				.map_err(|err| Error::Syntax(err.to_string()));
+			perf.mark(PERF_MARK_DESER_END).unwrap_throw();
+			res
+		})
+	}
+}
+
+/// File picked by the user
+#[derive(Debug, Clone)]
+pub(crate) struct FileSource {
+	file: web_sys::File,
+}
+impl FileSource {
+	#[inline(always)]
+	pub fn new(file: web_sys::File) -> Self {
+		Self { file }
+	}
+}
+impl Display for FileSource {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "file \"{}\"", self.file.name())
+	}
+}
+impl DataSource for FileSource {
+	fn load(&self) -> LoadFuture<'_> {
+		Box::pin(async move {
+			use wasm_bindgen_futures::JsFuture;
+
+			let format = Format::from_content_type(&self.file.type_())
+				.or_else(|| Format::from_path(&self.file.name()))
+				.unwrap_or(Format::Csv);
+			let text = JsFuture::from(self.file.text())
+				.await
+				.map_err(|err| Error::Io(js_error_message(&err)))?
+				.as_string()
+				.unwrap_throw();
+
+			parse(&text, format)
+		})
+	}
+}
+
+/// Any of the data sources
+#[derive(Debug, Clone)]
+pub(crate) enum Source {
+	Http(HttpSource),
+	#[cfg(feature = "embedded-data")]
+	Embedded(EmbeddedSource),
+	File(FileSource),
+}
+impl Display for Source {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Http(source) => Display::fmt(source, f),
+			#[cfg(feature = "embedded-data")]
+			Self::Embedded(source) => Display::fmt(source, f),
+			Self::File(source) => Display::fmt(source, f),
+		}
+	}
+}
+impl DataSource for Source {
+	fn load(&self) -> LoadFuture<'_> {
+		Box::pin(async move {
+			match self {
+				Self::Http(source) => source.load().await,
+				#[cfg(feature = "embedded-data")]
+				Self::Embedded(source) => source.load().await,
+				Self::File(source) => source.load().await,
+			}
+		})
+	}
+}
+impl From<HttpSource> for Source {
+	#[inline(always)]
+	fn from(source: HttpSource) -> Self {
+		Self::Http(source)
+	}
+}
+#[cfg(feature = "embedded-data")]
+impl From<EmbeddedSource> for Source {
+	#[inline(always)]
+	fn from(source: EmbeddedSource) -> Self {
+		Self::Embedded(source)
+	}
+}
+impl From<FileSource> for Source {
+	#[inline(always)]
+	fn from(source: FileSource) -> Self {
+		Self::File(source)
+	}
+}
+
+/// Get the data source of the page
+///
+/// This is the URL given in the `?data=` search parameter of the page if any,
+/// or else the data source built into the application.
+pub(crate) fn initial_source() -> Source {
+	use web_sys::UrlSearchParams;
+
+	let search = web_sys::window()
+		.unwrap_throw()
+		.location()
+		.search()
+		.unwrap_or_default();
+	UrlSearchParams::new_with_str(&search)
+		.ok()
+		.and_then(|params| params.get(QUERY_DATA))
+		.filter(|url| !url.is_empty())
+		.map(|url| HttpSource::new(url).into())
+		.unwrap_or_else(|| {
+			#[cfg(feature = "embedded-data")]
+			{
+				EmbeddedSource.into()
+			}
+			#[cfg(not(feature = "embedded-data"))]
+			{
+				HttpSource::new(env!("MAKE_DATA_PATH")).into()
+			}
+		})
+}
+
+/// Get the data source requested by the user, provided by the root scope
+#[inline]
+pub(crate) fn use_source(cx: Scope) -> &Signal<Source> {
+	use_context::<Signal<Source>>(cx)
+}
+
+/// Load the data of `source` into `store`
+pub(crate) async fn load<S: DataSource>(
+	store: &Signal<SpiritStore>,
+	source: &S,
+) -> Result<(), Error> {
+	use web_sys::{console, PerformanceMeasure};
+
+	let perf = performance();
+	perf.clear_marks();
+	perf.clear_measures();
+	let measure = |measure_name: &str, start_mark: &str, end_mark: &str| {
+		perf.measure_with_start_mark_and_end_mark(measure_name, start_mark, end_mark)
+			.ok()?;
+		perf.get_entries_by_name_with_entry_type(measure_name, "measure")
+			.get(0)
+			.dyn_into::<PerformanceMeasure>()
+			.ok()
+			.map(|measure| measure.duration())
+	};
+
+	let vec = source.load().await?;
+	store.set(
+		vec.into_iter()
+			.inspect(crate::cache_resources)
+			.map(Rc::new)
+			.collect(),
+	);
+	if let Some(measure_fetch) = measure(
+		PERF_MEASURE_FETCH,
+		PERF_MARK_FETCH_START,
+		PERF_MARK_FETCH_END,
+	) {
+		console::debug_1(&format!("Fetched character data in {measure_fetch}ms").into());
+	}
+	if let Some(measure_deser) = measure(
+		PERF_MEASURE_DESER,
+		PERF_MARK_DESER_START,
+		PERF_MARK_DESER_END,
+	) {
+		console::debug_1(&format!("Deserialized character data in {measure_deser}ms").into());
+	}
+	console::info_1(
+		&format!(
+			"Loaded data of {len} characters from {source}",
+			len = store.get().len()
+		)
+		.into(),
+	);
+	Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::models::{filter::Filter, group::GroupBy, sort::Sort, Spirit};
 use dal_spirits_proc::EnumVariantStr;
 use std::{cell::RefCell, fmt::Debug};
 use sycamore::prelude::*;
@@ -7,10 +8,23 @@ use wasm_bindgen::{JsCast, UnwrapThrowExt};
 #[derive(Debug, Clone, PartialEq, Eq, IRoute, EnumVariantStr)]
 pub(crate) enum Route {
 	#[to("/")]
-	#[not_found]
 	Home,
-	#[to("/spirits/<name>/<form>")]
-	SpiritDetails { name: String, form: String },
+	#[to("/spirits/<chara>/<form>")]
+	SpiritDetails { chara: String, form: String },
+	/// Character or alias, resolved to a spirit
+	#[to("/spirits/<chara>")]
+	Character { chara: String },
+	#[to("/birthdays")]
+	Birthdays,
+	#[to("/stats")]
+	Stats,
+	#[to("/sephirot")]
+	Sephirot,
+	/// Spirits side by side, see [`Route::compare`]
+	#[to("/compare/<spirits..>")]
+	Compare { spirits: Vec<String> },
+	#[not_found]
+	NotFound,
 }
 impl Default for Route {
 	#[inline(always)]
@@ -18,13 +32,191 @@ impl Default for Route {
 		Self::Home
 	}
 }
+impl Route {
+	/// Get the route to the details of a spirit
+	#[inline]
+	pub fn spirit(spirit: &Spirit) -> Self {
+		Self::SpiritDetails {
+			chara: spirit.chara_slug().to_string(),
+			form: spirit.form_slug().to_string(),
+		}
+	}
+
+	/// Get the route comparing spirits
+	#[inline]
+	pub fn compare<'s, I: IntoIterator<Item = &'s Spirit>>(spirits: I) -> Self {
+		Self::Compare {
+			spirits: spirits.into_iter().map(spirit_segment).collect(),
+		}
+	}
+
+	/// Get the path matching the route
+	///
+	/// [`NotFound`](Self::NotFound) has no path of its own, so it leads back home.
+	pub fn path(&self) -> String {
+		match self {
+			Self::Home | Self::NotFound => "/".to_string(),
+			Self::SpiritDetails { chara, form } => format!("/spirits/{chara}/{form}"),
+			Self::Character { chara } => format!("/spirits/{chara}"),
+			Self::Birthdays => "/birthdays".to_string(),
+			Self::Stats => "/stats".to_string(),
+			Self::Sephirot => "/sephirot".to_string(),
+			Self::Compare { spirits } => format!("/compare/{}", spirits.join("/")),
+		}
+	}
+
+	/// Get the path matching the route, followed by the query string of `params`
+	#[inline]
+	pub fn with_query<Q: QueryParams>(&self, params: &Q) -> String {
+		format!(
+			"{path}{query}",
+			path = self.path(),
+			query = format_query(&params.to_pairs())
+		)
+	}
+}
+
+/// Separator of the slugs of a spirit held in a single path segment
+const SEGMENT_SEP: char = '.';
+
+/// Identify a spirit in a single path segment, such as `Tohka.InverseSpirit`
+#[inline]
+pub(crate) fn spirit_segment(spirit: &Spirit) -> String {
+	format!(
+		"{chara}{SEGMENT_SEP}{form}",
+		chara = spirit.chara_slug(),
+		form = spirit.form_slug()
+	)
+}
+
+/// Split a path segment made by [`spirit_segment`] into its character slug and its optional form slug
+///
+/// Like in routes, the character slug may be an alias.
+#[inline]
+pub(crate) fn parse_segment(segment: &str) -> (&str, Option<&str>) {
+	match segment.split_once(SEGMENT_SEP) {
+		Some((chara, form)) => (chara, Some(form)),
+		None => (segment, None),
+	}
+}
+
+/// Typed parameters held in the query string of a route
+pub(crate) trait QueryParams: Default + Clone + PartialEq {
+	/// Decode the parameters, ignoring unknown keys and invalid values
+	fn from_pairs(pairs: &[(String, String)]) -> Self;
+	/// Encode the parameters, leaving out those with their default value
+	fn to_pairs(&self) -> Vec<(&'static str, String)>;
+}
+
+/// Query parameters of [`Route::Home`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct HomeParams {
+	pub search: String,
+	pub filter: Filter,
+	pub group: GroupBy,
+	/// Ordering of the spirits, or the order of the dataset
+	pub sort: Option<Sort>,
+	/// Whether spirits are listed in a single list, instead of grouped by series and character
+	pub flat: bool,
+}
+impl HomeParams {
+	const KEY_SEARCH: &'static str = "q";
+	const KEY_FLAT: &'static str = "flat";
+}
+impl QueryParams for HomeParams {
+	fn from_pairs(pairs: &[(String, String)]) -> Self {
+		let find = |key: &str| {
+			pairs
+				.iter()
+				.find(|(k, _)| k == key)
+				.map(|(_, value)| value.as_str())
+		};
+		let pairs_str = || {
+			pairs
+				.iter()
+				.map(|(key, value)| (key.as_str(), value.as_str()))
+		};
+		Self {
+			search: find(Self::KEY_SEARCH).unwrap_or_default().to_string(),
+			filter: Filter::from_query(pairs_str()),
+			group: GroupBy::from_query(pairs_str()),
+			sort: Sort::from_query(pairs_str()),
+			flat: find(Self::KEY_FLAT)
+				.and_then(|flat| flat.parse().ok())
+				.unwrap_or_default(),
+		}
+	}
+
+	fn to_pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = self.filter.to_query();
+		if !self.search.is_empty() {
+			pairs.insert(0, (Self::KEY_SEARCH, self.search.clone()));
+		}
+		pairs.extend(self.group.to_query());
+		pairs.extend(Sort::to_query(self.sort));
+		if self.flat {
+			pairs.push((Self::KEY_FLAT, self.flat.to_string()));
+		}
+		pairs
+	}
+}
+
+/// Base path of the application, without its trailing `/`
+#[cfg(feature = "history-router")]
+fn base_path() -> &'static str {
+	env!("MAKE_BASE_PATH").trim_end_matches('/')
+}
+
+/// Get the URL to a path of the application, for links and the history
+///
+/// The path may be followed by a query string.
+pub(crate) fn href(path: &str) -> String {
+	#[cfg(feature = "history-router")]
+	{
+		format!("{base}{path}", base = base_path())
+	}
+	#[cfg(not(feature = "history-router"))]
+	{
+		format!("#{path}")
+	}
+}
+
+/// Get the path of a hash-based URL, such as `#/spirits/Tohka/Base`
+#[inline]
+fn hash_path(hash: &str) -> Option<&str> {
+	hash.strip_prefix('#').filter(|path| path.starts_with('/'))
+}
+
+/// Get the path of the current location, query string included
+fn location_path() -> String {
+	let location = web_sys::window().unwrap_throw().location();
+	let hash = location.hash().unwrap_throw();
+	#[cfg(feature = "history-router")]
+	{
+		// Hash-based links are redirected by the router
+		if let Some(path) = hash_path(&hash) {
+			return path.to_string();
+		}
+		let pathname = location.pathname().unwrap_throw();
+		let path = pathname
+			.strip_prefix(base_path())
+			.filter(|path| path.starts_with('/'))
+			.unwrap_or("/");
+		format!("{path}{search}", search = location.search().unwrap_throw())
+	}
+	#[cfg(not(feature = "history-router"))]
+	{
+		hash_path(&hash).unwrap_or("/").to_string()
+	}
+}
 
 thread_local! {
-	static HASH_PATH: RefCell<Option<RcSignal<String>>> = RefCell::new(None);
+	static PATH: RefCell<Option<RcSignal<String>>> = RefCell::new(None);
 }
 
+/// Router following either the hash of the URL, or its path if the `history-router` feature is enabled
 #[component]
-pub(crate) fn HashRouter<'a, G: Html, R, F>(cx: Scope<'a>, view: F) -> View<G>
+pub(crate) fn Router<'a, G: Html, R, F>(cx: Scope<'a>, view: F) -> View<G>
 where
 	R: IRoute + PartialEq + 'a,
 	F: FnOnce(Scope<'a>, &'a ReadSignal<R>) -> View<G> + 'a,
@@ -32,65 +224,221 @@ where
 	use wasm_bindgen::closure::Closure;
 
 	let window = web_sys::window().unwrap_throw();
-	let location = window.location();
-	let get_hash_path = move || {
-		location
-			.hash()
-			.unwrap_throw()
-			.strip_prefix('#')
-			.unwrap_or("/")
-			.to_string()
-	};
 
-	HASH_PATH.with(|hash_path| {
+	PATH.with(|path| {
 		assert!(
-			hash_path.borrow().is_none(),
-			"cannot have more than one HashRouter component"
+			path.borrow().is_none(),
+			"cannot have more than one Router component"
 		);
-		*hash_path.borrow_mut() = Some(create_rc_signal(get_hash_path()));
-	});
-	on_cleanup(cx, || {
-		HASH_PATH.with(|hash_path| {
-			*hash_path.borrow_mut() = None;
-		});
+		*path.borrow_mut() = Some(create_rc_signal(location_path()));
 	});
 
-	let path_sig = HASH_PATH.with(|hash_path| hash_path.borrow().clone().unwrap_throw());
+	let path_sig = PATH.with(|path| path.borrow().clone().unwrap_throw());
 	let closure = Closure::wrap({
 		let path_sig = path_sig.clone();
 		Box::new(move || {
-			path_sig.set(get_hash_path());
+			path_sig.set(location_path());
+			#[cfg(feature = "history-router")]
+			redirect_hash();
 		})
 	} as Box<dyn FnMut()>);
-	window
-		.add_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref())
+	for event in ["popstate", "hashchange"] {
+		window
+			.add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+			.unwrap_throw();
+	}
+	#[cfg(feature = "history-router")]
+	let link_closure = {
+		redirect_hash();
+		intercept_links()
+	};
+	// Listeners are removed along with the router, which may be mounted again
+	on_cleanup(cx, move || {
+		PATH.with(|path| {
+			*path.borrow_mut() = None;
+		});
+		for event in ["popstate", "hashchange"] {
+			window
+				.remove_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+				.unwrap_throw();
+		}
+		#[cfg(feature = "history-router")]
+		window
+			.document()
+			.unwrap_throw()
+			.remove_event_listener_with_callback("click", link_closure.as_ref().unchecked_ref())
+			.unwrap_throw();
+	});
+
+	let route_sig = create_selector(cx, move || R::match_path(split_query(&path_sig.get()).0));
+	view(cx, route_sig)
+}
+
+/// Rewrite a hash-based URL to its path
+#[cfg(feature = "history-router")]
+fn redirect_hash() {
+	let hash = web_sys::window()
+		.unwrap_throw()
+		.location()
+		.hash()
 		.unwrap_throw();
-	window
-		.add_event_listener_with_callback("hashchange", closure.as_ref().unchecked_ref())
+	if let Some(path) = hash_path(&hash) {
+		navigate_replace(path);
+	}
+}
+
+/// Navigate within the application instead of loading a new page when a link is clicked
+///
+/// The listener is returned so that it can be removed.
+#[cfg(feature = "history-router")]
+fn intercept_links() -> wasm_bindgen::closure::Closure<dyn FnMut(web_sys::MouseEvent)> {
+	use wasm_bindgen::closure::Closure;
+	use web_sys::{Element, MouseEvent};
+
+	let closure = Closure::wrap(Box::new(move |ev: MouseEvent| {
+		if ev.default_prevented()
+			|| ev.button() != 0
+			|| ev.ctrl_key()
+			|| ev.meta_key()
+			|| ev.shift_key()
+			|| ev.alt_key()
+		{
+			return;
+		}
+		let anchor = ev
+			.target()
+			.and_then(|target| target.dyn_into::<Element>().ok())
+			.and_then(|el| el.closest("a[href]").ok().flatten());
+		let Some(anchor) = anchor else {
+			return;
+		};
+		if anchor.has_attribute("download") || anchor.has_attribute("target") {
+			return;
+		}
+		let href = anchor.get_attribute("href").unwrap_or_default();
+		let path = hash_path(&href).or_else(|| {
+			href.strip_prefix(base_path())
+				.filter(|path| path.starts_with('/'))
+		});
+		if let Some(path) = path {
+			ev.prevent_default();
+			navigate_push(path);
+		}
+	}) as Box<dyn FnMut(MouseEvent)>);
+	web_sys::window()
+		.and_then(|window| window.document())
+		.unwrap_throw()
+		.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
 		.unwrap_throw();
-	closure.forget();
-	let route_sig = create_selector(cx, move || R::match_path(&path_sig.get()));
-	view(cx, route_sig)
+	closure
+}
+
+/// Split a path into its path and its query string
+#[inline]
+fn split_query(path: &str) -> (&str, &str) {
+	path.split_once('?').unwrap_or((path, ""))
+}
+
+/// Get the current path, without its query string
+pub(crate) fn use_path(cx: Scope) -> &ReadSignal<String> {
+	let path_sig = PATH.with(|path| {
+		path.borrow()
+			.clone()
+			.expect("use_path can only be used with a Router")
+	});
+	create_selector(cx, move || split_query(&path_sig.get()).0.to_string())
+}
+
+/// Get the typed parameters of the query string of the current path
+pub(crate) fn use_query_params<Q: QueryParams + 'static>(cx: Scope) -> &ReadSignal<Q> {
+	let path_sig = PATH.with(|path| {
+		path.borrow()
+			.clone()
+			.expect("use_query_params can only be used with a Router")
+	});
+	create_selector(cx, move || {
+		Q::from_pairs(&parse_query(split_query(&path_sig.get()).1))
+	})
+}
+
+/// Decode the key-value pairs of a query string
+fn parse_query(query: &str) -> Vec<(String, String)> {
+	let decode = |s: &str| {
+		js_sys::decode_uri_component(&s.replace('+', " "))
+			.map(String::from)
+			.unwrap_or_else(|_| s.to_string())
+	};
+	query
+		.split('&')
+		.filter(|pair| !pair.is_empty())
+		.map(|pair| {
+			let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+			(decode(key), decode(value))
+		})
+		.collect()
+}
+
+/// Encode key-value pairs into a query string, with its leading `?` if not empty
+fn format_query<K: AsRef<str>, V: AsRef<str>>(pairs: &[(K, V)]) -> String {
+	let encode = |s: &str| String::from(js_sys::encode_uri_component(s));
+	let query = pairs
+		.iter()
+		.map(|(key, value)| {
+			format!(
+				"{key}={value}",
+				key = encode(key.as_ref()),
+				value = encode(value.as_ref())
+			)
+		})
+		.collect::<Vec<_>>()
+		.join("&");
+	if query.is_empty() {
+		query
+	} else {
+		format!("?{query}")
+	}
 }
 
-pub(crate) fn navigate_replace(hash: &str) {
+fn navigate(path: &str, replace: bool) {
 	use wasm_bindgen::JsValue;
 
-	HASH_PATH.with(|hash_path| {
+	PATH.with(|path_cell| {
 		assert!(
-			hash_path.borrow().is_some(),
-			"navigate_replace can only be used with a HashRouter"
+			path_cell.borrow().is_some(),
+			"navigation can only be used with a Router"
 		);
-		let path_sig = hash_path.borrow().clone().unwrap_throw();
-		if hash != path_sig.get_untracked().as_ref() {
-			path_sig.set(hash.to_string());
+		let path_sig = path_cell.borrow().clone().unwrap_throw();
+		let changed = path != path_sig.get_untracked().as_ref();
+		if !changed && !replace {
+			return;
 		}
+		// The history is updated first, so that the new page sees its own URL
+		let url = href(path);
 		web_sys::window()
 			.unwrap_throw()
 			.history()
 			.and_then(|history| {
-				history.replace_state_with_url(&JsValue::NULL, "", Some(&("#".to_owned() + hash)))
+				if replace {
+					history.replace_state_with_url(&JsValue::NULL, "", Some(&url))
+				} else {
+					history.push_state_with_url(&JsValue::NULL, "", Some(&url))
+				}
 			})
 			.unwrap_throw();
+		if changed {
+			path_sig.set(path.to_string());
+		}
 	});
 }
+
+/// Navigate to `path`, replacing the current history entry
+#[inline]
+pub(crate) fn navigate_replace(path: &str) {
+	navigate(path, true);
+}
+
+/// Navigate to `path` in a new history entry
+#[inline]
+pub(crate) fn navigate_push(path: &str) {
+	navigate(path, false);
+}
@@ -0,0 +1,256 @@
+use crate::models::{slug, Element, Medium, Series, Spirit};
+use std::{collections::HashMap, rc::Rc};
+use sycamore::prelude::*;
+
+/// Character data, indexed for the lookups of the views
+///
+/// A single store is provided through the context of the root scope; see [`use_store`].
+#[derive(Debug, Default)]
+pub(crate) struct SpiritStore {
+	spirits: Vec<Rc<Spirit>>,
+	/// Character slugs, by order of first appearance
+	charas: Vec<String>,
+	by_slug: HashMap<String, HashMap<String, usize>>,
+	/// Spirits by the aliases of their character, see [`slug::parse_alias`](crate::models::slug::parse_alias)
+	chara_aliases: HashMap<String, usize>,
+	/// Spirits by their former slugs
+	form_aliases: HashMap<String, HashMap<String, usize>>,
+	by_chara: HashMap<String, Vec<usize>>,
+	by_series: HashMap<Series, Vec<usize>>,
+	by_element: HashMap<Element, Vec<usize>>,
+	by_medium: HashMap<Medium, Vec<usize>>,
+}
+impl SpiritStore {
+	#[inline]
+	fn indexed<'s>(
+		&'s self,
+		indices: Option<&'s Vec<usize>>,
+	) -> impl Iterator<Item = &'s Rc<Spirit>> + 's {
+		indices
+			.into_iter()
+			.flatten()
+			.map(|&index| &self.spirits[index])
+	}
+
+	#[inline(always)]
+	pub fn len(&self) -> usize {
+		self.spirits.len()
+	}
+
+	#[inline(always)]
+	pub fn all(&self) -> &[Rc<Spirit>] {
+		&self.spirits
+	}
+
+	/// Get a spirit by its slugs
+	#[inline]
+	pub fn get(&self, chara_slug: &str, form_slug: &str) -> Option<&Rc<Spirit>> {
+		self.by_slug
+			.get(chara_slug)
+			.and_then(|forms| forms.get(form_slug))
+			.map(|&index| &self.spirits[index])
+	}
+
+	#[inline]
+	fn form_alias(&self, chara_slug: &str, form_slug: &str) -> Option<&Rc<Spirit>> {
+		self.form_aliases
+			.get(chara_slug)
+			.and_then(|forms| forms.get(form_slug))
+			.map(|&index| &self.spirits[index])
+	}
+
+	/// Get a spirit by its slugs or by one of its aliases
+	///
+	/// Without a form slug, the spirit holding the alias of the character is returned,
+	/// or else the first form of the character.
+	pub fn resolve(&self, chara_slug: &str, form_slug: Option<&str>) -> Option<&Rc<Spirit>> {
+		let chara_alias = self
+			.chara_aliases
+			.get(chara_slug)
+			.map(|&index| &self.spirits[index]);
+		match form_slug {
+			Some(form_slug) => self
+				.get(chara_slug, form_slug)
+				.or_else(|| self.form_alias(chara_slug, form_slug))
+				.or_else(|| {
+					let chara_slug = chara_alias?.chara_slug();
+					self.get(chara_slug, form_slug)
+						.or_else(|| self.form_alias(chara_slug, form_slug))
+				}),
+			None => chara_alias.or_else(|| self.chara(chara_slug).next()),
+		}
+	}
+
+	/// Iterate over character slugs, by order of first appearance
+	#[inline]
+	pub fn charas(&self) -> impl Iterator<Item = &str> {
+		self.charas.iter().map(String::as_str)
+	}
+
+	/// Iterate over the forms of a character
+	#[inline]
+	pub fn chara(&self, chara_slug: &str) -> impl Iterator<Item = &Rc<Spirit>> {
+		self.indexed(self.by_chara.get(chara_slug))
+	}
+
+	#[inline]
+	pub fn series(&self, series: Series) -> impl Iterator<Item = &Rc<Spirit>> {
+		self.indexed(self.by_series.get(&series))
+	}
+
+	#[inline]
+	pub fn element(&self, element: Element) -> impl Iterator<Item = &Rc<Spirit>> {
+		self.indexed(self.by_element.get(&element))
+	}
+
+	#[inline]
+	pub fn medium(&self, medium: Medium) -> impl Iterator<Item = &Rc<Spirit>> {
+		self.indexed(self.by_medium.get(&medium))
+	}
+}
+impl FromIterator<Rc<Spirit>> for SpiritStore {
+	fn from_iter<I: IntoIterator<Item = Rc<Spirit>>>(iter: I) -> Self {
+		let mut store = Self::default();
+		for (index, spirit) in iter.into_iter().enumerate() {
+			let chara_slug = spirit.chara_slug();
+			if !store.by_chara.contains_key(chara_slug) {
+				store.charas.push(chara_slug.to_string());
+			}
+			store
+				.by_slug
+				.entry(chara_slug.to_string())
+				.or_default()
+				.insert(spirit.form_slug().to_string(), index);
+			for alias in spirit.aliases() {
+				match slug::parse_alias(alias) {
+					(chara_alias, None) => {
+						store.chara_aliases.insert(chara_alias.to_string(), index);
+					}
+					(chara_alias, Some(form_alias)) => {
+						store
+							.form_aliases
+							.entry(chara_alias.to_string())
+							.or_default()
+							.insert(form_alias.to_string(), index);
+					}
+				}
+			}
+			store
+				.by_chara
+				.entry(chara_slug.to_string())
+				.or_default()
+				.push(index);
+			store
+				.by_series
+				.entry(spirit.series())
+				.or_default()
+				.push(index);
+			spirit.elements.iter().for_each(|element| {
+				store.by_element.entry(element).or_default().push(index);
+			});
+			spirit.media.iter().for_each(|medium| {
+				store.by_medium.entry(medium).or_default().push(index);
+			});
+			store.spirits.push(spirit);
+		}
+		store
+	}
+}
+
+/// Get the store provided by the root scope
+#[inline]
+pub(crate) fn use_store(cx: Scope) -> &Signal<SpiritStore> {
+	use_context::<Signal<SpiritStore>>(cx)
+}
+
+/// Derive a value from the store, which is only updated when it changes
+///
+/// Signals read by `f` are tracked as well.
+#[inline]
+pub(crate) fn select<'a, T, F>(cx: Scope<'a>, f: F) -> &'a ReadSignal<T>
+where
+	T: PartialEq + 'a,
+	F: Fn(&SpiritStore) -> T + 'a,
+{
+	let store = use_store(cx);
+	create_selector(cx, move || f(&store.get()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SpiritStore;
+	use crate::models;
+	use std::rc::Rc;
+
+	fn store() -> SpiritStore {
+		models::from_json(
+			r#"[
+				{"firstname": "Tohka", "form": "Spirit", "chara_slug": "Tohka", "form_slug": "Spirit"},
+				{
+					"firstname": "Tohka",
+					"form": "Inverse Spirit",
+					"chara_slug": "Tohka",
+					"form_slug": "InverseSpirit",
+					"aliases": ["Tenka", "Tohka/Inverse"]
+				},
+				{
+					"firstname": "Origami",
+					"form": "Spirit",
+					"chara_slug": "Origami",
+					"form_slug": "Spirit",
+					"aliases": ["Angel"]
+				}
+			]"#,
+		)
+		.unwrap()
+		.into_iter()
+		.map(Rc::new)
+		.collect()
+	}
+
+	/// Resolve slugs to the slugs of a spirit
+	fn resolve<'s>(
+		store: &'s SpiritStore,
+		chara_slug: &str,
+		form_slug: Option<&str>,
+	) -> Option<(&'s str, &'s str)> {
+		store
+			.resolve(chara_slug, form_slug)
+			.map(|spirit| (spirit.chara_slug(), spirit.form_slug()))
+	}
+
+	#[test]
+	fn resolve_slugs() {
+		let store = store();
+		assert_eq!(
+			resolve(&store, "Tohka", Some("Spirit")),
+			Some(("Tohka", "Spirit"))
+		);
+		assert_eq!(resolve(&store, "Tohka", None), Some(("Tohka", "Spirit")));
+		assert_eq!(resolve(&store, "Tohka", Some("Full")), None);
+		assert_eq!(resolve(&store, "Kurumi", None), None);
+	}
+
+	#[test]
+	fn resolve_aliases() {
+		let store = store();
+		assert_eq!(
+			resolve(&store, "Tenka", None),
+			Some(("Tohka", "InverseSpirit"))
+		);
+		assert_eq!(
+			resolve(&store, "Tenka", Some("Spirit")),
+			Some(("Tohka", "Spirit"))
+		);
+		assert_eq!(
+			resolve(&store, "Tohka", Some("Inverse")),
+			Some(("Tohka", "InverseSpirit"))
+		);
+		assert_eq!(
+			resolve(&store, "Tenka", Some("Inverse")),
+			Some(("Tohka", "InverseSpirit"))
+		);
+		assert_eq!(resolve(&store, "Angel", None), Some(("Origami", "Spirit")));
+		assert_eq!(resolve(&store, "Angel", Some("Inverse")), None);
+	}
+}